@@ -0,0 +1,75 @@
+//! Compare [MontgomeryModuloP] with [IntegersModuloP], run with `cargo bench`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use polymoly::polynomial::Polynomial;
+use polymoly::ring::{Field, IntegersModuloP, MontgomeryModuloP, Ring};
+
+/// The largest prime below 2^63
+const P: usize = (1 << 63) - 25;
+
+/// Time `f` over `runs` runs and return the average
+fn time(runs: u32, mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+
+    start.elapsed() / runs
+}
+
+/// Multiply a chain of elements, which is what hot loops like `pow_mod` boil down to
+fn product_chain<F: Ring<Element = E>, E: Clone>(field: F, start: E) -> E {
+    let mut acc = field.one();
+    let mut x = start;
+    for _ in 0..1_000_000 {
+        acc = field.mul(acc, x.clone());
+        x = field.add(x, acc.clone());
+    }
+
+    acc
+}
+
+fn pow_mod<F: Field>(field: F, coefficients: impl Fn(usize) -> F::Element) -> Polynomial<F> {
+    let base = Polynomial::new(field, (0..32).map(&coefficients).collect::<Vec<_>>());
+    let modulus = Polynomial::new(
+        field,
+        (0..=64).map(|i| coefficients(i + 100)).collect::<Vec<_>>(),
+    );
+
+    base.pow_mod(1 << 20, modulus).expect("modulus is not zero")
+}
+
+fn report(name: &str, plain: Duration, montgomery: Duration) {
+    println!(
+        "{name:<16} IntegersModuloP {plain:>12.2?}   MontgomeryModuloP {montgomery:>12.2?}   speedup {:.2}x",
+        plain.as_secs_f64() / montgomery.as_secs_f64()
+    );
+}
+
+fn main() {
+    let plain = IntegersModuloP::new_unchecked(P);
+    let montgomery = MontgomeryModuloP::new_unchecked(P);
+
+    report(
+        "product chain",
+        time(10, || {
+            black_box(product_chain(plain, black_box(3)));
+        }),
+        time(10, || {
+            black_box(product_chain(montgomery, black_box(montgomery.from_int(3))));
+        }),
+    );
+
+    let coefficient = |i: usize| (i * i * 7919 + 13) as isize;
+    report(
+        "pow_mod",
+        time(3, || {
+            black_box(pow_mod(plain, coefficient));
+        }),
+        time(3, || {
+            black_box(pow_mod(montgomery, |i| montgomery.from_int(coefficient(i))));
+        }),
+    );
+}
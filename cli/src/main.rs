@@ -1,18 +1,101 @@
-use std::fmt;
-
 use clap::ArgGroup;
-use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand};
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand, ValueEnum};
 
-use polymoly::polynomial::display::DisplayRing;
-use polymoly::polynomial::parse::ParsableRing;
-use polymoly::polynomial::Polynomial;
-use polymoly::ring::{Field, Integers, IntegersModuloN, IntegersModuloP, PolynomialRing, Reals};
+use polymoly::dynamic::{DynPolynomial, DynRing};
+use polymoly::polynomial::display::mathml::math;
+use polymoly::polynomial::display::{self, unicode, DisplayOptions, Representation};
+use polymoly::polynomial::parse::ParseOptions;
+use polymoly::primes;
+use polymoly::ring::{Integers, IntegersModuloN, IntegersModuloP, Reals, RingSpec, MAX_MODULUS};
 
 #[derive(Parser)]
 #[command(version, propagate_version = true, about = None, long_about = None)]
 struct CliArgs {
     #[command(subcommand)]
     operation: Operation,
+
+    /// Name of the polynomial variable
+    #[arg(
+        long,
+        global = true,
+        value_name = "NAME",
+        default_value = "x",
+        value_parser = parse_variable
+    )]
+    variable: String,
+
+    /// Display coefficients in Z/nZ with the balanced representatives -(n-1)/2..=n/2
+    #[arg(long, global = true)]
+    balanced: bool,
+
+    /// Output format of the results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+
+    /// Pretty-print with Unicode, the same as `--format unicode`
+    #[arg(long, global = true, conflicts_with = "format")]
+    unicode: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human readable text, e.g. `3x^2 - 1`
+    Plain,
+
+    /// Unicode text with superscripts, e.g. `3x² − 1`
+    Unicode,
+
+    /// LaTeX math, e.g. `3x^{2} - 1`
+    Latex,
+
+    /// A MathML `<math>` element
+    Mathml,
+}
+
+/// Options for parsing and displaying that are shared by all operations
+struct Settings {
+    parse: ParseOptions,
+    display: DisplayOptions,
+    format: Format,
+}
+
+impl Settings {
+    fn new(cli: &CliArgs) -> Self {
+        Self {
+            parse: ParseOptions {
+                variable: cli.variable.clone(),
+            },
+            display: DisplayOptions {
+                variable: cli.variable.clone(),
+                representation: if cli.balanced {
+                    Representation::Balanced
+                } else {
+                    Representation::Standard
+                },
+                ..Default::default()
+            },
+            format: if cli.unicode {
+                Format::Unicode
+            } else {
+                cli.format
+            },
+        }
+    }
+
+    /// The output format of the library
+    fn format(&self) -> display::Format {
+        match self.format {
+            Format::Plain => display::Format::Plain,
+            Format::Unicode => display::Format::Unicode,
+            Format::Latex => display::Format::Latex,
+            Format::Mathml => display::Format::MathMl,
+        }
+    }
+
+    /// Render a polynomial in the chosen output [Format]
+    fn show(&self, poly: &DynPolynomial) -> String {
+        poly.render(self.format(), &self.display)
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -23,6 +106,7 @@ enum Operation {
         ring: RingArg,
 
         /// The polynomials to sum
+        #[arg(allow_hyphen_values = true)]
         poly: Vec<String>,
     },
 
@@ -32,9 +116,11 @@ enum Operation {
         ring: RingArg,
 
         /// Left-hand side polynomial
+        #[arg(allow_hyphen_values = true)]
         lhs: String,
 
         /// Right-hand side polynomial
+        #[arg(allow_hyphen_values = true)]
         rhs: String,
     },
 
@@ -44,6 +130,7 @@ enum Operation {
         ring: RingArg,
 
         /// The polynomials to multiply
+        #[arg(allow_hyphen_values = true)]
         poly: Vec<String>,
     },
 
@@ -53,22 +140,44 @@ enum Operation {
         field: FieldArg,
 
         /// Left-hand side polynomial
+        #[arg(allow_hyphen_values = true)]
         lhs: String,
 
         /// Right-hand side polynomial
+        #[arg(allow_hyphen_values = true)]
         rhs: String,
     },
 
-    /// Greatest common divisor (using EEA) in a euclidean ring
+    /// Greatest common divisor (using EEA) of a number of elements in a euclidean ring
     Gcd {
         #[command(flatten)]
         ring: EuclideanRingArg,
 
-        /// Left-hand side
-        lhs: String,
+        /// The elements to take the GCD of
+        #[arg(required = true, allow_hyphen_values = true)]
+        operands: Vec<String>,
+    },
 
-        /// Right-hand side
-        rhs: String,
+    /// Least common multiple of a number of elements in a euclidean ring
+    Lcm {
+        #[command(flatten)]
+        ring: EuclideanRingArg,
+
+        /// The elements to take the LCM of
+        #[arg(required = true, allow_hyphen_values = true)]
+        operands: Vec<String>,
+    },
+
+    /// Check if an integer is prime (prints `true` or `false`)
+    Isprime {
+        /// The integer to test
+        n: usize,
+    },
+
+    /// Factorize an integer into primes
+    Factorint {
+        /// The integer to factorize
+        n: usize,
     },
 }
 
@@ -86,19 +195,19 @@ struct RingArg {
     /// Interpret polynomials over integers modulo n
     #[arg(short = 'M', long, value_name = "N")]
     modulo: Option<usize>,
+
+    /// Interpret polynomials over the ring SPEC, e.g. `Q`, `Z[i]`, `Z/12Z` or `GF(2^8)`
+    #[arg(long, value_name = "SPEC", value_parser = parse_ring)]
+    ring: Option<DynRing>,
 }
 
 impl RingArg {
-    fn run<R, I, M>(&self, reals: R, integers: I, modulo: M)
-    where
-        R: Fn(Reals),
-        I: Fn(Integers),
-        M: Fn(IntegersModuloN),
-    {
-        match (self.reals, self.integers, self.modulo) {
-            (false, true, None) => integers(Integers),
-            (false, false, Some(n)) => modulo(IntegersModuloN::new(n)),
-            _ => reals(Reals),
+    fn ring(&self) -> DynRing {
+        match (self.integers, self.modulo, self.ring) {
+            (true, None, None) => DynRing::Integers(Integers),
+            (false, Some(n), None) => modulo_ring(n),
+            (false, None, Some(ring)) => ring,
+            _ => DynRing::Reals(Reals),
         }
     }
 }
@@ -110,40 +219,37 @@ struct FieldArg {
     #[arg(short = 'R', long, group = "field")]
     reals: bool,
 
-    /// Interpret polynomials over integers modulo p (where p is prime)
+    /// Interpret polynomials over integers modulo n (if n is not prime, the leading coefficient
+    /// of the divisor must be a unit)
     #[arg(
         short = 'M',
         long,
-        value_name = "P",
+        value_name = "N",
         group = "field",
         group = "prime check"
     )]
     modulo: Option<usize>,
 
-    /// Don't check if p is actually a prime number
+    /// Assume that n is prime without checking it
     #[arg(long, requires = "prime check")]
     disable_prime_check: bool,
+
+    /// Interpret polynomials over the ring SPEC, e.g. `Q` or `GF(2^8)` (if it is not a field,
+    /// the leading coefficient of the divisor must be a unit)
+    #[arg(long, value_name = "SPEC", value_parser = parse_ring, group = "field")]
+    ring: Option<DynRing>,
 }
 
 impl FieldArg {
-    fn run<R, M>(&self, reals: R, modulo: M)
-    where
-        R: Fn(Reals),
-        M: Fn(IntegersModuloP),
-    {
-        match (self.reals, self.modulo) {
-            (false, Some(p)) => {
-                if self.disable_prime_check {
-                    modulo(IntegersModuloP::new_unchecked(p))
-                } else if let Some(p) = IntegersModuloP::new(p) {
-                    modulo(p)
-                } else {
-                    let mut cmd = CliArgs::command();
-                    cmd.error(ErrorKind::InvalidValue, "Argument p must be prime")
-                        .exit();
-                }
-            }
-            _ => reals(Reals),
+    fn ring(&self) -> DynRing {
+        match (self.reals, self.modulo, self.ring) {
+            (false, Some(n), None) if self.disable_prime_check => prime_field(n, true),
+            (false, Some(n), None) => match IntegersModuloP::new(n) {
+                Some(p) => DynRing::IntegersModuloP(p),
+                None => modulo_ring(n),
+            },
+            (false, None, Some(ring)) => ring,
+            _ => DynRing::Reals(Reals),
         }
     }
 }
@@ -172,169 +278,241 @@ struct EuclideanRingArg {
     /// Don't check if p is actually a prime number
     #[arg(long, requires = "prime check")]
     disable_prime_check: bool,
+
+    /// Interpret as elements of the ring SPEC, e.g. `Z[i]` for Gaussian integers a + bi or
+    /// `Q` for polynomials over the rationals
+    #[arg(long, value_name = "SPEC", value_parser = parse_ring, group = "euclidean ring")]
+    ring: Option<DynRing>,
 }
 
 impl EuclideanRingArg {
-    fn run<I, R, M>(&self, integers: I, reals: R, modulo: M)
-    where
-        I: Fn(Integers),
-        R: Fn(Reals),
-        M: Fn(IntegersModuloP),
-    {
-        match (self.integers, self.poly_reals, self.poly_modulo) {
-            (true, false, None) => integers(Integers),
-            (false, true, None) => reals(Reals),
-            (false, false, Some(p)) => {
-                if self.disable_prime_check {
-                    modulo(IntegersModuloP::new_unchecked(p))
-                } else if let Some(p) = IntegersModuloP::new(p) {
-                    modulo(p)
-                } else {
-                    let mut cmd = CliArgs::command();
-                    cmd.error(ErrorKind::InvalidValue, "Argument p must be prime")
-                        .exit();
-                }
-            }
-            _ => unreachable!("clap: required and no multiple"),
+    fn ring(&self) -> DynRing {
+        if self.integers {
+            DynRing::Integers(Integers)
+        } else if let Some(ring) = self.ring {
+            ring
+        } else if self.poly_reals {
+            DynRing::Reals(Reals)
+        } else if let Some(p) = self.poly_modulo {
+            prime_field(p, self.disable_prime_check)
+        } else {
+            unreachable!("clap: required and no multiple")
+        }
+    }
+}
+
+/// The ring `Z/nZ`, exits if `n` is out of range
+fn modulo_ring(n: usize) -> DynRing {
+    match IntegersModuloN::try_new(n) {
+        Ok(modulo) => DynRing::IntegersModuloN(modulo),
+        Err(_) => {
+            let mut cmd = CliArgs::command();
+            cmd.error(
+                ErrorKind::InvalidValue,
+                format!("Argument n must be between 1 and {MAX_MODULUS}"),
+            )
+            .exit();
+        }
+    }
+}
+
+/// The field `Z/pZ`, exits if `p` is out of range or not prime unless the check is disabled
+fn prime_field(p: usize, disable_prime_check: bool) -> DynRing {
+    if !(1..=MAX_MODULUS).contains(&p) {
+        let mut cmd = CliArgs::command();
+        cmd.error(
+            ErrorKind::InvalidValue,
+            format!("Argument p must be between 1 and {MAX_MODULUS}"),
+        )
+        .exit();
+    }
+
+    if disable_prime_check {
+        return DynRing::IntegersModuloP(IntegersModuloP::new_unchecked(p));
+    }
+
+    match IntegersModuloP::try_new(p) {
+        Ok(p) => DynRing::IntegersModuloP(p),
+        Err(err) => {
+            let mut cmd = CliArgs::command();
+            cmd.error(
+                ErrorKind::InvalidValue,
+                format!("Argument p is invalid: {err}"),
+            )
+            .exit();
         }
     }
 }
 
 fn main() {
     let cli = CliArgs::parse();
+    let settings = &Settings::new(&cli);
 
-    match cli.operation {
+    let output = match cli.operation {
         Operation::Add { ring, poly } => {
-            ring.run(|r| add(r, &poly), |z| add(z, &poly), |n| add(n, &poly))
+            let ring = ring.ring();
+            parse_all(ring, &poly, settings)
+                .iter()
+                .try_fold(ring.zero(), |sum, poly| sum.add(poly))
+                .map(|sum| settings.show(&sum))
+        }
+        Operation::Sub { ring, lhs, rhs } => {
+            let ring = ring.ring();
+            let lhs = parse_polynomial(ring, &lhs, settings);
+            let rhs = parse_polynomial(ring, &rhs, settings);
+            lhs.sub(&rhs).map(|diff| settings.show(&diff))
         }
-        Operation::Sub { ring, lhs, rhs } => ring.run(
-            |r| sub(r, &lhs, &rhs),
-            |z| sub(z, &lhs, &rhs),
-            |n| sub(n, &lhs, &rhs),
-        ),
         Operation::Mul { ring, poly } => {
-            ring.run(|r| mul(r, &poly), |z| mul(z, &poly), |n| mul(n, &poly))
+            let ring = ring.ring();
+            parse_all(ring, &poly, settings)
+                .iter()
+                .try_fold(ring.one(), |product, poly| product.mul(poly))
+                .map(|product| settings.show(&product))
         }
         Operation::Div { field, lhs, rhs } => {
-            field.run(|r| div(r, &lhs, &rhs), |p| div(p, &lhs, &rhs))
+            let field = field.ring();
+            let lhs = parse_polynomial(field, &lhs, settings);
+            let rhs = parse_polynomial(field, &rhs, settings);
+            lhs.div_rem(&rhs).and_then(|(q, r)| {
+                field.render_division([&lhs, &rhs, &q, &r], settings.format(), &settings.display)
+            })
+        }
+        Operation::Gcd { ring, operands } => {
+            let ring = ring.ring();
+            let operands = parse_all(ring, &operands, settings);
+            ring.extended_gcd(&operands)
+                .and_then(|(gcd, coefficients)| {
+                    ring.render_bezout(
+                        &gcd,
+                        &operands,
+                        &coefficients,
+                        settings.format(),
+                        &settings.display,
+                    )
+                })
+        }
+        Operation::Lcm { ring, operands } => {
+            let ring = ring.ring();
+            ring.lcm(&parse_all(ring, &operands, settings))
+                .map(|lcm| settings.show(&lcm))
+        }
+        Operation::Isprime { n } => Ok(primes::is_prime(n).to_string()),
+        Operation::Factorint { n } => match primes::factorize(n) {
+            Some(factors) => Ok(show_factors(&factors, settings.format)),
+            None => {
+                let mut cmd = CliArgs::command();
+                cmd.error(ErrorKind::InvalidValue, "Argument n must not be 0")
+                    .exit();
+            }
+        },
+    };
+
+    match output {
+        Ok(output) => println!("{output}"),
+        Err(polymoly::Error::NotInvertible) => {
+            let mut cmd = CliArgs::command();
+            cmd.error(
+                ErrorKind::InvalidValue,
+                "The leading coefficient of the divisor is not a unit",
+            )
+            .exit();
+        }
+        Err(err) => {
+            let mut cmd = CliArgs::command();
+            cmd.error(ErrorKind::InvalidValue, capitalize(&err.to_string()))
+                .exit();
         }
-        Operation::Gcd { ring, lhs, rhs } => ring.run(
-            |_| gcd_int(&lhs, &rhs),
-            |r| gcd_poly(r, &lhs, &rhs),
-            |p| gcd_poly(p, &lhs, &rhs),
-        ),
     }
 }
 
-fn add<R>(ring: R, polynomials: &[String])
-where
-    R: ParsableRing + DisplayRing,
-    R::Element: fmt::Display,
-{
-    let result = polynomials
-        .iter()
-        .map(|s| parse_polynomial(ring, s))
-        .fold(Polynomial::zero(ring), std::ops::Add::add);
+/// Render a prime factorization like `2^3 * 3` in the output [Format], the empty product is `1`
+fn show_factors(factors: &[(usize, u32)], format: Format) -> String {
+    if factors.is_empty() {
+        return match format {
+            Format::Mathml => math("<mn>1</mn>"),
+            _ => String::from("1"),
+        };
+    }
 
-    println!("{result}");
+    let powers = factors.iter().map(|&(p, e)| match (format, e) {
+        (Format::Mathml, 1) => format!("<mn>{p}</mn>"),
+        (Format::Mathml, _) => format!("<msup><mn>{p}</mn><mn>{e}</mn></msup>"),
+        (_, 1) => p.to_string(),
+        (Format::Plain, _) => format!("{p}^{e}"),
+        (Format::Unicode, _) => format!("{p}{}", unicode::superscript(e as usize)),
+        (Format::Latex, _) => format!("{p}^{{{e}}}"),
+    });
+
+    let powers: Vec<String> = powers.collect();
+    match format {
+        Format::Plain => powers.join(" * "),
+        Format::Unicode => powers.join(" · "),
+        Format::Latex => powers.join(r" \cdot "),
+        Format::Mathml => math(&powers.join("<mo>·</mo>")),
+    }
 }
 
-fn sub<R>(ring: R, lhs: &str, rhs: &str)
-where
-    R: ParsableRing + DisplayRing,
-    R::Element: fmt::Display,
-{
-    let lhs = parse_polynomial(ring, lhs);
-    let rhs = parse_polynomial(ring, rhs);
-
-    println!("{}", lhs - rhs);
+/// Parse a ring specification like `Z[i]` or `GF(2^8)`, see [RingSpec]
+fn parse_ring(spec: &str) -> Result<DynRing, String> {
+    let spec = spec.parse::<RingSpec>().map_err(|err| err.to_string())?;
+    DynRing::try_from(spec).map_err(|err| err.to_string())
 }
 
-fn mul<R>(ring: R, polynomials: &[String])
-where
-    R: ParsableRing + DisplayRing,
-    R::Element: fmt::Display,
-{
-    let result = polynomials
-        .iter()
-        .map(|s| parse_polynomial(ring, s))
-        .fold(Polynomial::constant(ring, ring.one()), std::ops::Mul::mul);
-
-    println!("{result}");
+/// Check that the name of the variable can't be confused with numbers and operators
+fn parse_variable(name: &str) -> Result<String, String> {
+    match name.chars().next() {
+        None => Err(String::from("the name must not be empty")),
+        Some(c) if c.is_numeric() || c == '.' => Err(String::from(
+            "the name must not start with a digit or a point",
+        )),
+        Some(c) if "+-−*·/^()".contains(c) => {
+            Err(String::from("the name must not start with an operator"))
+        }
+        _ if name.contains(char::is_whitespace) => {
+            Err(String::from("the name must not contain whitespace"))
+        }
+        _ => Ok(name.to_string()),
+    }
 }
 
-fn div<F>(field: F, lhs: &str, rhs: &str)
-where
-    F: Field + ParsableRing + DisplayRing,
-    F::Element: fmt::Display,
-{
-    let lhs = parse_polynomial(field, lhs);
-    let rhs = parse_polynomial(field, rhs);
-    let Some((q, r)) = lhs.clone().polynomial_division(rhs.clone()) else {
-        let mut cmd = CliArgs::command();
-        cmd.error(ErrorKind::InvalidValue, "Right-hand side must not be zero")
-            .exit();
-    };
-
-    println!("{q}");
-    if !r.is_zero() {
-        println!("REM {r}");
+/// Start an error message with an uppercase letter like the messages of clap
+fn capitalize(message: &str) -> String {
+    let mut chars = message.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
 
-fn gcd_int(lhs: &str, rhs: &str) {
-    let lhs = parse_int(lhs);
-    let rhs = parse_int(rhs);
-
-    let Some((gcd, s, t)) = polymoly::euclid::extended_euclidean_int(lhs, rhs) else {
-        let mut cmd = CliArgs::command();
-        cmd.error(ErrorKind::InvalidValue, "One side must be non-zero")
-            .exit();
-    };
-
-    println!("{gcd}\nWITH s = {s} AND t = {t}");
+fn parse_all(ring: DynRing, inputs: &[String], settings: &Settings) -> Vec<DynPolynomial> {
+    inputs
+        .iter()
+        .map(|s| parse_polynomial(ring, s, settings))
+        .collect()
 }
 
-fn gcd_poly<F>(field: F, lhs: &str, rhs: &str)
-where
-    F: Field + ParsableRing + DisplayRing,
-    F::Element: fmt::Display,
-{
-    let lhs = parse_polynomial(field, lhs);
-    let rhs = parse_polynomial(field, rhs);
-
-    let poly_ring = PolynomialRing::new(field);
-    let Some((gcd, s, t)) = polymoly::euclid::extended_euclidean(poly_ring, lhs, rhs) else {
-        let mut cmd = CliArgs::command();
-        cmd.error(ErrorKind::InvalidValue, "One side must be non-zero")
+fn parse_polynomial(ring: DynRing, input: &str, settings: &Settings) -> DynPolynomial {
+    match ring.parse(input, &settings.parse) {
+        Ok(poly) => poly,
+        Err(polymoly::Error::Parse(err)) => {
+            let mut cmd = CliArgs::command();
+            cmd.error(
+                ErrorKind::InvalidValue,
+                format!(
+                    "`{input}` cannot be parsed as polynomial in `{ring}`: {err}\n\n  {input}\n  {}",
+                    underline(input, &err.span)
+                ),
+            )
             .exit();
-    };
-
-    println!("{gcd}\nWITH s = {s} AND t = {t}");
-}
-
-fn parse_int(input: &str) -> isize {
-    if let Ok(int) = input.parse() {
-        int
-    } else {
-        let mut cmd = CliArgs::command();
-        cmd.error(
-            ErrorKind::InvalidValue,
-            format!("`{input}` cannot be parsed as integer"),
-        )
-        .exit();
+        }
+        Err(err) => unreachable!("parsing fails with a parse error: {err}"),
     }
 }
 
-fn parse_polynomial<R: ParsableRing>(ring: R, input: &str) -> Polynomial<R> {
-    if let Some(poly) = Polynomial::parse(ring, input) {
-        poly
-    } else {
-        let mut cmd = CliArgs::command();
-        cmd.error(
-            ErrorKind::InvalidValue,
-            format!("`{input}` cannot be parsed as polynomial in `{ring:?}`"),
-        )
-        .exit();
-    }
+/// Mark the `span` of `input` with carets (at least one)
+fn underline(input: &str, span: &std::ops::Range<usize>) -> String {
+    let offset = input[..span.start].chars().count();
+    let width = input[span.clone()].chars().count().max(1);
+
+    format!("{}{}", " ".repeat(offset), "^".repeat(width))
 }
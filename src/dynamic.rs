@@ -0,0 +1,612 @@
+//! Rings and polynomials that are chosen at runtime
+//!
+//! [Polynomial] is generic over its ring, so a front-end would need one code path per ring. A
+//! [DynRing] wraps the concrete rings and a [DynPolynomial] the polynomials over them. The
+//! operations are dispatched at runtime and fail with an [Error] if the ring doesn't support
+//! them.
+//!
+//! ```
+//! # use polymoly::dynamic::DynRing;
+//! # use polymoly::polynomial::display::{DisplayOptions, Format};
+//! # use polymoly::polynomial::parse::ParseOptions;
+//! let ring: DynRing = "Z/7Z".parse::<polymoly::ring::RingSpec>().unwrap().try_into().unwrap();
+//!
+//! let lhs = ring.parse("x^2 + 1", &ParseOptions::default()).unwrap();
+//! let rhs = ring.parse("x + 6", &ParseOptions::default()).unwrap();
+//! let (q, r) = lhs.div_rem(&rhs).unwrap();
+//!
+//! assert_eq!(q.render(Format::Plain, &DisplayOptions::default()), "x + 1");
+//! assert_eq!(r.render(Format::Plain, &DisplayOptions::default()), "2");
+//! ```
+
+use std::fmt;
+
+use crate::euclid;
+use crate::polynomial::display::{latex, mathml, DisplayOptions, DisplayRing, Format};
+use crate::polynomial::parse::ParseOptions;
+use crate::polynomial::Polynomial;
+use crate::ring::{
+    Field, FiniteRing, GaloisField, GaussianIntegers, Integers, IntegersModuloAny, IntegersModuloN,
+    IntegersModuloP, PolynomialRing, Rationals, Reals, Ring, RingSpec, RingSpecError,
+};
+use crate::{Error, Result};
+
+/// A ring of coefficients that is chosen at runtime
+#[derive(Debug, Clone, Copy)]
+pub enum DynRing {
+    Reals(Reals),
+    Integers(Integers),
+    GaussianIntegers(GaussianIntegers),
+    Rationals(Rationals),
+    IntegersModuloN(IntegersModuloN),
+    IntegersModuloP(IntegersModuloP),
+    GaloisField(GaloisField),
+}
+
+/// A polynomial over a [DynRing]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynPolynomial {
+    Reals(Polynomial<Reals>),
+    Integers(Polynomial<Integers>),
+    GaussianIntegers(Polynomial<GaussianIntegers>),
+    Rationals(Polynomial<Rationals>),
+    IntegersModuloN(Polynomial<IntegersModuloN>),
+    IntegersModuloP(Polynomial<IntegersModuloP>),
+    GaloisField(Polynomial<GaloisField>),
+}
+
+/// Evaluate `$body` with `$r` bound to the concrete ring
+macro_rules! each_ring {
+    ($ring:expr, $r:ident => $body:expr) => {
+        match $ring {
+            DynRing::Reals($r) => $body,
+            DynRing::Integers($r) => $body,
+            DynRing::GaussianIntegers($r) => $body,
+            DynRing::Rationals($r) => $body,
+            DynRing::IntegersModuloN($r) => $body,
+            DynRing::IntegersModuloP($r) => $body,
+            DynRing::GaloisField($r) => $body,
+        }
+    };
+}
+
+/// Evaluate `$body` with `$p` bound to the concrete polynomial
+macro_rules! each_polynomial {
+    ($poly:expr, $p:ident => $body:expr) => {
+        match $poly {
+            DynPolynomial::Reals($p) => $body,
+            DynPolynomial::Integers($p) => $body,
+            DynPolynomial::GaussianIntegers($p) => $body,
+            DynPolynomial::Rationals($p) => $body,
+            DynPolynomial::IntegersModuloN($p) => $body,
+            DynPolynomial::IntegersModuloP($p) => $body,
+            DynPolynomial::GaloisField($p) => $body,
+        }
+    };
+}
+
+/// Evaluate `$body` with `$ops` bound to the concrete polynomials of `$operands`, they must be
+/// over the same ring as `$ring`
+macro_rules! with_operands {
+    ($ring:expr, $operands:expr, $ops:ident => $body:expr) => {
+        match $ring {
+            DynRing::Reals(_) => {
+                let $ops: Vec<Polynomial<Reals>> = typed($operands)?;
+                $body
+            }
+            DynRing::Integers(_) => {
+                let $ops: Vec<Polynomial<Integers>> = typed($operands)?;
+                $body
+            }
+            DynRing::GaussianIntegers(_) => {
+                let $ops: Vec<Polynomial<GaussianIntegers>> = typed($operands)?;
+                $body
+            }
+            DynRing::Rationals(_) => {
+                let $ops: Vec<Polynomial<Rationals>> = typed($operands)?;
+                $body
+            }
+            DynRing::IntegersModuloN(_) => {
+                let $ops: Vec<Polynomial<IntegersModuloN>> = typed($operands)?;
+                $body
+            }
+            DynRing::IntegersModuloP(_) => {
+                let $ops: Vec<Polynomial<IntegersModuloP>> = typed($operands)?;
+                $body
+            }
+            DynRing::GaloisField(_) => {
+                let $ops: Vec<Polynomial<GaloisField>> = typed($operands)?;
+                $body
+            }
+        }
+    };
+}
+
+/// Conversions between the concrete polynomials and [DynPolynomial]
+trait Variant: Sized {
+    fn from_dyn(poly: &DynPolynomial) -> Option<&Self>;
+}
+
+macro_rules! impl_variant {
+    ($variant:ident, $ring:ty) => {
+        impl From<Polynomial<$ring>> for DynPolynomial {
+            fn from(poly: Polynomial<$ring>) -> Self {
+                DynPolynomial::$variant(poly)
+            }
+        }
+
+        impl Variant for Polynomial<$ring> {
+            fn from_dyn(poly: &DynPolynomial) -> Option<&Self> {
+                match poly {
+                    DynPolynomial::$variant(poly) => Some(poly),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_variant!(Reals, Reals);
+impl_variant!(Integers, Integers);
+impl_variant!(GaussianIntegers, GaussianIntegers);
+impl_variant!(Rationals, Rationals);
+impl_variant!(IntegersModuloN, IntegersModuloN);
+impl_variant!(IntegersModuloP, IntegersModuloP);
+impl_variant!(GaloisField, GaloisField);
+
+/// The concrete polynomials of `operands`, which must all be of the same variant
+fn typed<P: Variant + Clone>(operands: &[DynPolynomial]) -> Result<Vec<P>> {
+    operands
+        .iter()
+        .map(|poly| P::from_dyn(poly).cloned().ok_or(Error::RingMismatch))
+        .collect()
+}
+
+/// The two operands of a binary operation
+fn pair<P>(ops: Vec<P>) -> (P, P) {
+    let mut ops = ops.into_iter();
+    match (ops.next(), ops.next()) {
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+        _ => unreachable!("two operands"),
+    }
+}
+
+fn wrap<P: Into<DynPolynomial>>(polys: Vec<P>) -> Vec<DynPolynomial> {
+    polys.into_iter().map(Into::into).collect()
+}
+
+impl DynRing {
+    /// The ring `Z/nZ`, which is a field if `n` is prime
+    ///
+    /// Fail if `n` is out of range, see [IntegersModuloN::try_new].
+    pub fn modulo(n: usize) -> Result<Self> {
+        match IntegersModuloP::new(n) {
+            Some(field) => Ok(DynRing::IntegersModuloP(field)),
+            None => Ok(DynRing::IntegersModuloN(IntegersModuloN::try_new(n)?)),
+        }
+    }
+
+    /// The [RingSpec] that describes this ring
+    pub fn spec(&self) -> RingSpec {
+        match self {
+            DynRing::Reals(_) => RingSpec::Reals,
+            DynRing::Integers(_) => RingSpec::Integers,
+            DynRing::GaussianIntegers(_) => RingSpec::GaussianIntegers,
+            DynRing::Rationals(_) => RingSpec::Rationals,
+            DynRing::IntegersModuloN(ring) => RingSpec::IntegersModulo(ring.n()),
+            DynRing::IntegersModuloP(ring) => RingSpec::IntegersModulo(ring.n()),
+            DynRing::GaloisField(field) => RingSpec::GaloisField {
+                p: field.p(),
+                k: field.k(),
+            },
+        }
+    }
+
+    /// The characteristic, which is zero for the infinite rings
+    pub fn characteristic(&self) -> usize {
+        match self {
+            DynRing::Reals(_)
+            | DynRing::Integers(_)
+            | DynRing::GaussianIntegers(_)
+            | DynRing::Rationals(_) => 0,
+            DynRing::IntegersModuloN(ring) => ring.characteristic(),
+            DynRing::IntegersModuloP(ring) => ring.characteristic(),
+            DynRing::GaloisField(field) => field.characteristic(),
+        }
+    }
+
+    /// The number of elements, `None` if the ring is infinite
+    pub fn order(&self) -> Option<usize> {
+        match self {
+            DynRing::Reals(_)
+            | DynRing::Integers(_)
+            | DynRing::GaussianIntegers(_)
+            | DynRing::Rationals(_) => None,
+            DynRing::IntegersModuloN(ring) => Some(ring.order()),
+            DynRing::IntegersModuloP(ring) => Some(ring.order()),
+            DynRing::GaloisField(field) => Some(field.order()),
+        }
+    }
+
+    pub fn zero(&self) -> DynPolynomial {
+        each_ring!(*self, r => Polynomial::zero(r).into())
+    }
+
+    pub fn one(&self) -> DynPolynomial {
+        each_ring!(*self, r => Polynomial::constant(r, r.one()).into())
+    }
+
+    /// Parse a polynomial over this ring, see [crate::polynomial::parse::ParsableRing]
+    pub fn parse(&self, input: &str, options: &ParseOptions) -> Result<DynPolynomial> {
+        each_ring!(*self, r => Ok(Polynomial::parse_with(r, input, options)?.into()))
+    }
+
+    /// The GCD of `operands` with its Bézout coefficients
+    ///
+    /// This works for polynomials over fields and for constant polynomials over the (Gaussian)
+    /// integers.
+    pub fn extended_gcd(
+        &self,
+        operands: &[DynPolynomial],
+    ) -> Result<(DynPolynomial, Vec<DynPolynomial>)> {
+        match self {
+            DynRing::Reals(field) => {
+                let (gcd, coefficients) = field_gcd(*field, typed(operands)?)?;
+                Ok((gcd.into(), wrap(coefficients)))
+            }
+            DynRing::Rationals(field) => {
+                let (gcd, coefficients) = field_gcd(*field, typed(operands)?)?;
+                Ok((gcd.into(), wrap(coefficients)))
+            }
+            DynRing::IntegersModuloP(field) => {
+                let (gcd, coefficients) = field_gcd(*field, typed(operands)?)?;
+                Ok((gcd.into(), wrap(coefficients)))
+            }
+            DynRing::GaloisField(field) => {
+                let (gcd, coefficients) = field_gcd(*field, typed(operands)?)?;
+                Ok((gcd.into(), wrap(coefficients)))
+            }
+            DynRing::Integers(ring) => constant_gcd(*ring, self, typed(operands)?),
+            DynRing::GaussianIntegers(ring) => constant_gcd(*ring, self, typed(operands)?),
+            DynRing::IntegersModuloN(_) => Err(Error::NotEuclidean(self.spec())),
+        }
+    }
+
+    /// The LCM of `operands`, see [Self::extended_gcd] for the supported rings
+    pub fn lcm(&self, operands: &[DynPolynomial]) -> Result<DynPolynomial> {
+        match self {
+            DynRing::Reals(field) => {
+                Ok(euclid::try_lcm_many(PolynomialRing::new(*field), typed(operands)?)?.into())
+            }
+            DynRing::Rationals(field) => {
+                Ok(euclid::try_lcm_many(PolynomialRing::new(*field), typed(operands)?)?.into())
+            }
+            DynRing::IntegersModuloP(field) => {
+                Ok(euclid::try_lcm_many(PolynomialRing::new(*field), typed(operands)?)?.into())
+            }
+            DynRing::GaloisField(field) => {
+                Ok(euclid::try_lcm_many(PolynomialRing::new(*field), typed(operands)?)?.into())
+            }
+            DynRing::Integers(ring) => constant_lcm(*ring, self, typed(operands)?),
+            DynRing::GaussianIntegers(ring) => constant_lcm(*ring, self, typed(operands)?),
+            DynRing::IntegersModuloN(_) => Err(Error::NotEuclidean(self.spec())),
+        }
+    }
+
+    /// Render the result of [DynPolynomial::div_rem]
+    ///
+    /// The plain and Unicode formats put `q` on the first line and the remainder (if non-zero)
+    /// as `REM r` on the second line. The other formats render `lhs = q · rhs + r`.
+    pub fn render_division(
+        &self,
+        [lhs, rhs, q, r]: [&DynPolynomial; 4],
+        format: Format,
+        options: &DisplayOptions,
+    ) -> Result<String> {
+        let operands = [lhs.clone(), rhs.clone(), q.clone(), r.clone()];
+
+        with_operands!(self, &operands, ops => {
+            let [lhs, rhs, q, r] = &ops[..] else {
+                unreachable!("four operands")
+            };
+
+            Ok(match format {
+                Format::Plain | Format::Unicode => {
+                    let mut text = render(q, format, options);
+                    if !r.is_zero() {
+                        text.push_str(&format!("\nREM {}", render(r, format, options)));
+                    }
+                    text
+                }
+                Format::Latex => latex::division(lhs, rhs, q, r, options),
+                Format::MathMl => mathml::division(lhs, rhs, q, r, options),
+            })
+        })
+    }
+
+    /// Render the result of [Self::extended_gcd]
+    ///
+    /// The plain and Unicode formats put the GCD on the first line and the coefficients as
+    /// `WITH s = .. AND t = ..` (or `s_1, s_2, ...` for more than two operands) on the second
+    /// line. The other formats render `gcd(a, b) = g = s · a + t · b`.
+    pub fn render_bezout(
+        &self,
+        gcd: &DynPolynomial,
+        operands: &[DynPolynomial],
+        coefficients: &[DynPolynomial],
+        format: Format,
+        options: &DisplayOptions,
+    ) -> Result<String> {
+        let all: Vec<DynPolynomial> = std::iter::once(gcd)
+            .chain(operands)
+            .chain(coefficients)
+            .cloned()
+            .collect();
+
+        with_operands!(self, &all, all => {
+            let (gcd, rest) = all.split_first().expect("the GCD");
+            let (operands, coefficients) = rest.split_at(operands.len());
+
+            Ok(match format {
+                Format::Plain | Format::Unicode => {
+                    let coefficients: Vec<String> = coefficients
+                        .iter()
+                        .map(|c| render(c, format, options))
+                        .collect();
+                    format!(
+                        "{}\n{}",
+                        render(gcd, format, options),
+                        bezout_coefficients(&coefficients)
+                    )
+                }
+                Format::Latex => latex::bezout(gcd, operands, coefficients, options),
+                Format::MathMl => mathml::bezout(gcd, operands, coefficients, options),
+            })
+        })
+    }
+}
+
+impl TryFrom<&RingSpec> for DynRing {
+    type Error = Error;
+
+    /// Only the rings of coefficients are supported, not polynomial rings
+    fn try_from(spec: &RingSpec) -> std::result::Result<Self, Self::Error> {
+        match spec {
+            RingSpec::Reals => Ok(DynRing::Reals(Reals)),
+            RingSpec::Integers => Ok(DynRing::Integers(Integers)),
+            RingSpec::GaussianIntegers => Ok(DynRing::GaussianIntegers(GaussianIntegers)),
+            RingSpec::Rationals => Ok(DynRing::Rationals(Rationals)),
+            RingSpec::IntegersModulo(n) => DynRing::modulo(*n),
+            RingSpec::GaloisField { p, k } => {
+                Ok(DynRing::GaloisField(GaloisField::try_new(*p, *k)?))
+            }
+            RingSpec::Polynomials { .. } => {
+                Err(RingSpecError::Unsupported(format!("{spec} as coefficients")).into())
+            }
+        }
+    }
+}
+
+impl TryFrom<RingSpec> for DynRing {
+    type Error = Error;
+
+    fn try_from(spec: RingSpec) -> std::result::Result<Self, Self::Error> {
+        DynRing::try_from(&spec)
+    }
+}
+
+impl fmt::Display for DynRing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.spec())
+    }
+}
+
+impl DynPolynomial {
+    /// The ring of the coefficients
+    pub fn ring(&self) -> DynRing {
+        match self {
+            DynPolynomial::Reals(_) => DynRing::Reals(Reals),
+            DynPolynomial::Integers(_) => DynRing::Integers(Integers),
+            DynPolynomial::GaussianIntegers(_) => DynRing::GaussianIntegers(GaussianIntegers),
+            DynPolynomial::Rationals(_) => DynRing::Rationals(Rationals),
+            DynPolynomial::IntegersModuloN(p) => DynRing::IntegersModuloN(p.ring()),
+            DynPolynomial::IntegersModuloP(p) => DynRing::IntegersModuloP(p.ring()),
+            DynPolynomial::GaloisField(p) => DynRing::GaloisField(p.ring()),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        each_polynomial!(self, p => p.is_zero())
+    }
+
+    /// The sum, fails with [Error::Overflow] if a coefficient overflows
+    pub fn add(&self, rhs: &DynPolynomial) -> Result<DynPolynomial> {
+        let ops = [self.clone(), rhs.clone()];
+        with_operands!(self.ring(), &ops, ops => {
+            let (lhs, rhs) = pair(ops);
+            Ok(lhs.checked_add(rhs).ok_or(Error::Overflow)?.into())
+        })
+    }
+
+    /// The difference, fails with [Error::Overflow] if a coefficient overflows
+    pub fn sub(&self, rhs: &DynPolynomial) -> Result<DynPolynomial> {
+        let ops = [self.clone(), rhs.clone()];
+        with_operands!(self.ring(), &ops, ops => {
+            let (lhs, rhs) = pair(ops);
+            let difference = rhs.checked_neg().and_then(|rhs| lhs.checked_add(rhs));
+            Ok(difference.ok_or(Error::Overflow)?.into())
+        })
+    }
+
+    /// The product, fails with [Error::Overflow] if a coefficient overflows
+    pub fn mul(&self, rhs: &DynPolynomial) -> Result<DynPolynomial> {
+        let ops = [self.clone(), rhs.clone()];
+        with_operands!(self.ring(), &ops, ops => {
+            let (lhs, rhs) = pair(ops);
+            Ok(lhs.checked_mul(rhs).ok_or(Error::Overflow)?.into())
+        })
+    }
+
+    /// Polynomial division with remainder, see [Polynomial::try_polynomial_division]
+    ///
+    /// Over rings that are not fields, the leading coefficient of `rhs` must be a unit.
+    pub fn div_rem(&self, rhs: &DynPolynomial) -> Result<(DynPolynomial, DynPolynomial)> {
+        let ops = [self.clone(), rhs.clone()];
+        with_operands!(self.ring(), &ops, ops => {
+            let (lhs, rhs) = pair(ops);
+            let (q, r) = lhs.try_polynomial_division(rhs)?;
+            Ok((q.into(), r.into()))
+        })
+    }
+
+    /// Render the polynomial in a [Format]
+    pub fn render(&self, format: Format, options: &DisplayOptions) -> String {
+        each_polynomial!(self, p => render(p, format, options))
+    }
+}
+
+fn render<R>(poly: &Polynomial<R>, format: Format, options: &DisplayOptions) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    match format {
+        Format::Plain => poly.display(options).to_string(),
+        Format::Unicode => poly.to_unicode(options),
+        Format::Latex => poly.to_latex(options),
+        Format::MathMl => poly.to_mathml(options),
+    }
+}
+
+fn field_gcd<F: Field>(
+    field: F,
+    operands: Vec<Polynomial<F>>,
+) -> Result<(Polynomial<F>, Vec<Polynomial<F>>)> {
+    euclid::try_extended_euclidean_many(PolynomialRing::new(field), operands)
+}
+
+/// The GCD of constant polynomials over a Euclidean ring like the integers
+fn constant_gcd<E>(
+    ring: E,
+    dyn_ring: &DynRing,
+    polys: Vec<Polynomial<E>>,
+) -> Result<(DynPolynomial, Vec<DynPolynomial>)>
+where
+    E: euclid::EuclideanRing,
+    Polynomial<E>: Into<DynPolynomial>,
+{
+    let elems = constants(dyn_ring, polys)?;
+    let (gcd, coefficients) = euclid::try_extended_euclidean_many(ring, elems)?;
+
+    let constant = |elem| Polynomial::constant(ring, elem).into();
+    Ok((
+        constant(gcd),
+        coefficients.into_iter().map(constant).collect(),
+    ))
+}
+
+/// The LCM of constant polynomials, see [constant_gcd]
+fn constant_lcm<E>(ring: E, dyn_ring: &DynRing, polys: Vec<Polynomial<E>>) -> Result<DynPolynomial>
+where
+    E: euclid::EuclideanRing,
+    Polynomial<E>: Into<DynPolynomial>,
+{
+    let elems = constants(dyn_ring, polys)?;
+    Ok(Polynomial::constant(ring, euclid::try_lcm_many(ring, elems)?).into())
+}
+
+/// The constant terms of polynomials, which must all be constant
+fn constants<R: Ring>(ring: &DynRing, polys: Vec<Polynomial<R>>) -> Result<Vec<R::Element>> {
+    polys
+        .into_iter()
+        .map(|poly| match poly.deg() {
+            None | Some(0) => Ok(poly.coeff(0)),
+            Some(_) => Err(Error::NotEuclidean(ring.spec())),
+        })
+        .collect()
+}
+
+/// Format the coefficients `s` and `t` for two operands and `s_1, ..., s_n` otherwise
+fn bezout_coefficients(coefficients: &[String]) -> String {
+    if let [s, t] = coefficients {
+        return format!("WITH s = {s} AND t = {t}");
+    }
+
+    let coefficients: Vec<String> = coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("s_{} = {c}", i + 1))
+        .collect();
+
+    format!("WITH {}", coefficients.join(" AND "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_and_errors() {
+        let options = ParseOptions::default();
+        let display = DisplayOptions::default();
+
+        let z = DynRing::Integers(Integers);
+        let a = z.parse("12", &options).unwrap();
+        let b = z.parse("18", &options).unwrap();
+        let (gcd, coefficients) = z.extended_gcd(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(
+            z.render_bezout(&gcd, &[a, b], &coefficients, Format::Plain, &display),
+            Ok(String::from("6\nWITH s = -1 AND t = 1"))
+        );
+
+        let x = z.parse("x", &options).unwrap();
+        assert_eq!(
+            z.extended_gcd(std::slice::from_ref(&x)),
+            Err(Error::NotEuclidean(RingSpec::Integers))
+        );
+        let two_x = z.parse("2x", &options).unwrap();
+        assert_eq!(x.div_rem(&two_x), Err(Error::NotInvertible));
+        let big = z.parse("2^62x", &options).unwrap();
+        assert_eq!(big.mul(&two_x), Err(Error::Overflow));
+
+        let reals = DynRing::Reals(Reals);
+        let y = reals.parse("x + 1", &options).unwrap();
+        assert_eq!(x.add(&y), Err(Error::RingMismatch));
+        assert_eq!(y.div_rem(&reals.zero()), Err(Error::DivisionByZero));
+
+        let z12 = DynRing::modulo(12).unwrap();
+        assert_eq!(DynRing::modulo(0).err(), Some(Error::ZeroModulus));
+        let p = z12.parse("5x + 7", &options).unwrap();
+        let product = p.mul(&p).unwrap();
+        assert_eq!(product.render(Format::Plain, &display), "x^2 + 10x + 1");
+        assert_eq!(z12.to_string(), "Z/12Z");
+
+        let z_i = DynRing::try_from("Z[i]".parse::<RingSpec>().unwrap()).unwrap();
+        let a = z_i.parse("3 + 4i", &options).unwrap();
+        let b = z_i.parse("1 + 3i", &options).unwrap();
+        assert_eq!(
+            z_i.lcm(&[a, b]).unwrap().render(Format::Plain, &display),
+            "7 + i"
+        );
+
+        let gf = DynRing::try_from("GF(3^2)".parse::<RingSpec>().unwrap()).unwrap();
+        let a = gf.parse("x^2 - α", &options).unwrap();
+        assert_eq!(
+            a.mul(&a).unwrap().render(Format::Unicode, &display),
+            "x⁴ + αx² + 2"
+        );
+        assert_eq!((gf.characteristic(), gf.order()), (3, Some(9)));
+
+        let q = DynRing::Rationals(Rationals);
+        let a = q.parse("x^40", &options).unwrap();
+        let b = q
+            .parse("99999999999/99999999998x - 1/99999999997", &options)
+            .unwrap();
+        assert_eq!(a.div_rem(&b), Err(Error::Overflow));
+        assert_eq!(
+            q.extended_gcd(&[a.clone(), b.clone()]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(q.lcm(&[a, b]), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,94 @@
+//! The error type of fallible library operations
+
+use std::fmt;
+
+use crate::polynomial::parse::ParseError;
+use crate::ring::{RingSpec, RingSpecError};
+
+/// A library operation that failed
+///
+/// The functions that return `Option` are kept for convenience, most of them have a `try_`
+/// counterpart that reports the reason of a failure with this type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The divisor is zero
+    DivisionByZero,
+
+    /// The element has no multiplicative inverse, e.g. the leading coefficient of a divisor
+    NotInvertible,
+
+    /// The modulus of a ring `Z/nZ` is zero
+    ZeroModulus,
+
+    /// The modulus of a field `Z/pZ` is not prime
+    NotPrime(usize),
+
+    /// The `d` of a quadratic field `Q(√d)` is not squarefree or it is 0 or 1
+    NotSquarefree(isize),
+
+    /// The result doesn't fit into the machine integers
+    Overflow,
+
+    /// All operands of a GCD are zero
+    AllZero,
+
+    /// A polynomial couldn't be parsed
+    Parse(ParseError),
+
+    /// A ring specification is invalid or unsupported
+    Spec(RingSpecError),
+
+    /// The operands are polynomials over different rings
+    RingMismatch,
+
+    /// The operation needs a Euclidean ring, i.e. the coefficients must be a field or the
+    /// operands must be integers
+    NotEuclidean(RingSpec),
+}
+
+/// The result of a fallible library operation
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DivisionByZero => write!(f, "division by zero"),
+            Error::NotInvertible => write!(f, "the element is not a unit"),
+            Error::ZeroModulus => write!(f, "the modulus must not be zero"),
+            Error::NotPrime(p) => write!(f, "{p} is not prime"),
+            Error::NotSquarefree(d) => {
+                write!(f, "{d} is not a squarefree integer other than 0 and 1")
+            }
+            Error::Overflow => write!(f, "arithmetic overflow"),
+            Error::AllZero => write!(f, "one operand must be non-zero"),
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Spec(err) => write!(f, "{err}"),
+            Error::RingMismatch => write!(f, "the operands are from different rings"),
+            Error::NotEuclidean(ring) => {
+                write!(f, "polynomials over {ring} are not a Euclidean ring")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse(err) => Some(err),
+            Error::Spec(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<RingSpecError> for Error {
+    fn from(err: RingSpecError) -> Self {
+        Error::Spec(err)
+    }
+}
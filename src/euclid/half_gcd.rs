@@ -0,0 +1,194 @@
+//! Half-GCD (Knuth–Schönhage) algorithm for polynomials over fields
+
+use crate::polynomial::Polynomial;
+use crate::ring::Field;
+
+use super::HALF_GCD_THRESHOLD;
+
+/// A 2x2 matrix that maps two consecutive remainders to two later ones
+type Matrix<F> = [[Polynomial<F>; 2]; 2];
+
+/// Extended euclidean algorithm for polynomials using the half-GCD algorithm
+///
+/// This computes the same result as [super::classical_extended_euclidean] but needs only a
+/// quasi-linear number of polynomial multiplications. It relies on exact cancellation, so it
+/// may not terminate over inexact fields like [crate::ring::Reals].
+///
+/// This will be `None` iff `a` and `b` are 0.
+pub fn half_gcd_extended_euclidean<F: Field>(
+    field: F,
+    a: Polynomial<F>,
+    b: Polynomial<F>,
+) -> Option<(Polynomial<F>, Polynomial<F>, Polynomial<F>)> {
+    if a.is_zero() && b.is_zero() {
+        return None;
+    }
+
+    if b.is_zero() {
+        return Some((a, Polynomial::constant(field, field.one()), b));
+    }
+
+    // One classical step ensures `deg a > deg b`
+    let (q, r) = a.polynomial_division(b.clone()).expect("b is non-zero");
+    let mut matrix = step(q, identity(field));
+    let (mut a, mut b) = (b, r);
+
+    while !b.is_zero() {
+        let reduction = half_gcd(field, a.clone(), b.clone());
+        (a, b) = apply(&reduction, a, b);
+        matrix = multiply(&reduction, &matrix);
+
+        if b.is_zero() {
+            break;
+        }
+
+        let (q, r) = a.polynomial_division(b.clone()).expect("b is non-zero");
+        matrix = step(q, matrix);
+        (a, b) = (b, r);
+    }
+
+    let [[s, t], _] = matrix;
+    Some((a, s, t))
+}
+
+/// Compute the matrix that reduces `(a, b)` to the two consecutive remainders `(c, d)` with
+/// `deg c >= ceil(deg a / 2) > deg d`
+///
+/// This requires `deg a > deg b`.
+fn half_gcd<F: Field>(field: F, a: Polynomial<F>, b: Polynomial<F>) -> Matrix<F> {
+    let n = a.deg().expect("a is non-zero");
+    let m = n.div_ceil(2);
+
+    if b.deg().is_none_or(|deg| deg < m) {
+        return identity(field);
+    }
+
+    if n < HALF_GCD_THRESHOLD {
+        return classical_half_gcd(field, a, b, m);
+    }
+
+    let first = half_gcd(field, shift_right(field, &a, m), shift_right(field, &b, m));
+    let (c, d) = apply(&first, a, b);
+
+    if d.deg().is_none_or(|deg| deg < m) {
+        return first;
+    }
+
+    let (q, e) = c.polynomial_division(d.clone()).expect("d is non-zero");
+    let first = step(q, first);
+
+    // This can't happen with exact arithmetic, but finish the reduction classically instead of
+    // underflowing if rounding kept `deg d` above `2m`
+    let l = d.deg().expect("d is non-zero");
+    let Some(k) = (2 * m).checked_sub(l) else {
+        return multiply(&classical_half_gcd(field, d, e, m), &first);
+    };
+    let second = half_gcd(field, shift_right(field, &d, k), shift_right(field, &e, k));
+
+    multiply(&second, &first)
+}
+
+fn classical_half_gcd<F: Field>(
+    field: F,
+    mut a: Polynomial<F>,
+    mut b: Polynomial<F>,
+    m: usize,
+) -> Matrix<F> {
+    let mut matrix = identity(field);
+
+    while b.deg().is_some_and(|deg| deg >= m) {
+        let (q, r) = a.polynomial_division(b.clone()).expect("b is non-zero");
+        matrix = step(q, matrix);
+        (a, b) = (b, r);
+    }
+
+    matrix
+}
+
+fn identity<F: Field>(field: F) -> Matrix<F> {
+    let zero = Polynomial::zero(field);
+    let one = Polynomial::constant(field, field.one());
+    [[one.clone(), zero.clone()], [zero, one]]
+}
+
+/// Multiply `[[0, 1], [1, -q]]` from the left onto `matrix`
+fn step<F: Field>(q: Polynomial<F>, matrix: Matrix<F>) -> Matrix<F> {
+    let [[m00, m01], [m10, m11]] = matrix;
+    let n10 = m00 - q.clone().karatsuba_mul(m10.clone());
+    let n11 = m01 - q.karatsuba_mul(m11.clone());
+    [[m10, m11], [n10, n11]]
+}
+
+fn multiply<F: Field>(lhs: &Matrix<F>, rhs: &Matrix<F>) -> Matrix<F> {
+    let entry = |i: usize, j: usize| {
+        lhs[i][0].clone().karatsuba_mul(rhs[0][j].clone())
+            + lhs[i][1].clone().karatsuba_mul(rhs[1][j].clone())
+    };
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+
+fn apply<F: Field>(
+    matrix: &Matrix<F>,
+    a: Polynomial<F>,
+    b: Polynomial<F>,
+) -> (Polynomial<F>, Polynomial<F>) {
+    let c = matrix[0][0].clone().karatsuba_mul(a.clone())
+        + matrix[0][1].clone().karatsuba_mul(b.clone());
+    let d = matrix[1][0].clone().karatsuba_mul(a) + matrix[1][1].clone().karatsuba_mul(b);
+    (c, d)
+}
+
+/// Divide by `x^k`, dropping the remainder
+fn shift_right<F: Field>(field: F, poly: &Polynomial<F>, k: usize) -> Polynomial<F> {
+    let len = poly.deg().map_or(0, |deg| deg + 1);
+    Polynomial::new(field, (k..len).map(|i| poly.coeff(i)).collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::euclid::{classical_extended_euclidean, extended_euclidean};
+    use crate::ring::{IntegersModuloP, PolynomialRing, Reals};
+
+    fn random_polynomial(
+        field: IntegersModuloP,
+        deg: usize,
+        seed: &mut u64,
+    ) -> Polynomial<IntegersModuloP> {
+        let elems: Vec<isize> = (0..=deg)
+            .map(|_| {
+                *seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (*seed >> 33) as isize
+            })
+            .collect();
+        Polynomial::new(field, elems)
+    }
+
+    #[test]
+    fn agrees_with_classical() {
+        let field = IntegersModuloP::new(1009).unwrap();
+        let poly_ring = PolynomialRing::new(field);
+        let mut seed = 42;
+
+        for (deg_a, deg_b, deg_common) in [(300, 280, 0), (257, 300, 40), (400, 100, 150)] {
+            let common = random_polynomial(field, deg_common, &mut seed);
+            let a = random_polynomial(field, deg_a, &mut seed) * common.clone();
+            let b = random_polynomial(field, deg_b, &mut seed) * common;
+
+            let fast = half_gcd_extended_euclidean(field, a.clone(), b.clone());
+            let classical = classical_extended_euclidean(poly_ring, a, b);
+            assert_eq!(fast, classical);
+        }
+    }
+
+    #[test]
+    fn inexact_degrees() {
+        let parse = |input| Polynomial::parse(Reals, input).unwrap();
+        let (a, b) = (parse("(x + 0.5)^70 + x"), parse("(x - 0.3)^66 + 1"));
+
+        let (gcd, _, _) = extended_euclidean(PolynomialRing::new(Reals), a, b).unwrap();
+        assert_eq!(gcd, parse("1"));
+    }
+}
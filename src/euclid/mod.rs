@@ -0,0 +1,537 @@
+//! The (extended) euclidean algorithm
+
+mod half_gcd;
+
+use crate::polynomial::Polynomial;
+use crate::ring::{Field, GaussianInteger, GaussianIntegers, Integers, PolynomialRing, Ring};
+use crate::{Error, Result};
+
+pub use half_gcd::half_gcd_extended_euclidean;
+
+/// Minimal degree of both arguments from which on [half_gcd_extended_euclidean] is used for
+/// polynomials over fields with exact arithmetic
+pub const HALF_GCD_THRESHOLD: usize = 64;
+
+/// A integral domain that has a well-defined euclidean division
+pub trait EuclideanRing: Ring {
+    fn euclidean_function(elem: Self::Element) -> Option<usize>;
+    fn euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element)>;
+
+    /// Euclidean division, see [Self::euclidean_division]
+    ///
+    /// This fails with [Error::DivisionByZero] iff `b` is 0 and with [Error::Overflow] if the
+    /// quotient or remainder doesn't fit into [Ring::Element].
+    fn try_euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Result<(Self::Element, Self::Element)> {
+        Self::euclidean_division(a, b).ok_or(Error::DivisionByZero)
+    }
+
+    /// The unit `u` so that `elem = u * normal_form(elem)`
+    ///
+    /// This is one for zero.
+    fn unit_part(&self, elem: &Self::Element) -> Self::Element;
+
+    /// The inverse of a `unit`
+    fn unit_inverse(&self, unit: Self::Element) -> Self::Element;
+
+    /// The canonical associate of `elem`
+    ///
+    /// Elements that only differ by a unit factor have the same normal form.
+    fn normal_form(&self, elem: Self::Element) -> Self::Element {
+        let unit = self.unit_part(&elem);
+        self.mul(elem, self.unit_inverse(unit))
+    }
+
+    /// The extended euclidean algorithm used by [extended_euclidean]
+    ///
+    /// Rings can override this to choose an asymptotically faster algorithm.
+    fn extended_euclidean(
+        &self,
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element, Self::Element)> {
+        classical_extended_euclidean(*self, a, b)
+    }
+}
+
+impl EuclideanRing for Integers {
+    fn euclidean_function(elem: Self::Element) -> Option<usize> {
+        Some(elem.unsigned_abs())
+    }
+
+    fn euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element)> {
+        (b != 0).then(|| (a.div_euclid(b), a.rem_euclid(b)))
+    }
+
+    fn try_euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Result<(Self::Element, Self::Element)> {
+        if b == 0 {
+            return Err(Error::DivisionByZero);
+        }
+
+        // Only `isize::MIN / -1` overflows
+        a.checked_div_euclid(b)
+            .zip(a.checked_rem_euclid(b))
+            .ok_or(Error::Overflow)
+    }
+
+    fn unit_part(&self, elem: &Self::Element) -> Self::Element {
+        if *elem < 0 {
+            -1
+        } else {
+            1
+        }
+    }
+
+    fn unit_inverse(&self, unit: Self::Element) -> Self::Element {
+        unit
+    }
+}
+
+/// The euclidean function is the norm, the quotient is rounded to the nearest Gaussian integer
+impl EuclideanRing for GaussianIntegers {
+    fn euclidean_function(elem: Self::Element) -> Option<usize> {
+        // Norms beyond `usize::MAX` only occur for elements near the bounds of `isize`
+        Some(usize::try_from(elem.norm()).unwrap_or(usize::MAX))
+    }
+
+    fn euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element)> {
+        (b != GaussianIntegers.zero())
+            .then(|| Self::try_euclidean_division(a, b).expect("the quotient fits into an isize"))
+    }
+
+    fn try_euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Result<(Self::Element, Self::Element)> {
+        if b == GaussianIntegers.zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        // a / b = a * conj(b) / norm(b), round both parts to the nearest integer. This is computed
+        // in `i128` since the product can overflow an `isize` even if the quotient doesn't.
+        let (a_re, a_im) = (a.re as i128, a.im as i128);
+        let (b_re, b_im) = (b.re as i128, b.im as i128);
+        let norm = b_re * b_re + b_im * b_im;
+        let round = |n: i128| (2 * n + norm).div_euclid(2 * norm);
+
+        let q_re = round(a_re * b_re + a_im * b_im);
+        let q_im = round(a_im * b_re - a_re * b_im);
+        let r_re = a_re - (q_re * b_re - q_im * b_im);
+        let r_im = a_im - (q_re * b_im + q_im * b_re);
+
+        let narrow = |n: i128| isize::try_from(n).map_err(|_| Error::Overflow);
+        Ok((
+            GaussianInteger::new(narrow(q_re)?, narrow(q_im)?),
+            GaussianInteger::new(narrow(r_re)?, narrow(r_im)?),
+        ))
+    }
+
+    /// The normal form lies in the first quadrant, i.e. it has a positive real part and a
+    /// non-negative imaginary part
+    fn unit_part(&self, elem: &Self::Element) -> Self::Element {
+        let (re, im) = (elem.re, elem.im);
+        if re > 0 && im >= 0 || re == 0 && im == 0 {
+            self.one()
+        } else if re <= 0 && im > 0 {
+            GaussianInteger::i()
+        } else if re < 0 && im <= 0 {
+            self.neg(self.one())
+        } else {
+            self.neg(GaussianInteger::i())
+        }
+    }
+
+    fn unit_inverse(&self, unit: Self::Element) -> Self::Element {
+        unit.conj()
+    }
+}
+
+impl<F: Field> EuclideanRing for PolynomialRing<F> {
+    fn euclidean_function(elem: Self::Element) -> Option<usize> {
+        elem.deg()
+    }
+
+    fn euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element)> {
+        a.polynomial_division(b)
+    }
+
+    fn try_euclidean_division(
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Result<(Self::Element, Self::Element)> {
+        a.checked_polynomial_division(b)
+    }
+
+    fn unit_part(&self, elem: &Self::Element) -> Self::Element {
+        if elem.is_zero() {
+            self.one()
+        } else {
+            Polynomial::constant(self.base_ring(), elem.lc())
+        }
+    }
+
+    fn unit_inverse(&self, unit: Self::Element) -> Self::Element {
+        let field = self.base_ring();
+        let inverse = field.inv(unit.lc()).expect("unit is non-zero");
+        Polynomial::constant(field, inverse)
+    }
+
+    fn normal_form(&self, elem: Self::Element) -> Self::Element {
+        elem.monic()
+    }
+
+    fn extended_euclidean(
+        &self,
+        a: Self::Element,
+        b: Self::Element,
+    ) -> Option<(Self::Element, Self::Element, Self::Element)> {
+        let min_deg = a.deg().min(b.deg());
+        if self.is_exact() && min_deg.is_some_and(|deg| deg >= HALF_GCD_THRESHOLD) {
+            half_gcd_extended_euclidean(self.base_ring(), a, b)
+        } else {
+            classical_extended_euclidean(*self, a, b)
+        }
+    }
+}
+
+/// Generalized extended euclidean algorithm (EEA)
+///
+/// This will compute an `Option<(gcd, s, t)>` so that `gcd` is the greatest common divisor of `a`
+/// and `b` and the equation `(s * a) + (t * a) = gcd` holds.
+///
+/// The algorithm is chosen by the ring (see [EuclideanRing::extended_euclidean]). The result is
+/// scaled so that `gcd` is in normal form (see [EuclideanRing::normal_form]).
+///
+/// This will be `None` iff `a` and `b` are 0.
+pub fn extended_euclidean<E: EuclideanRing>(
+    ring: E,
+    a: E::Element,
+    b: E::Element,
+) -> Option<(E::Element, E::Element, E::Element)> {
+    let (gcd, s, t) = ring.extended_euclidean(a, b)?;
+    let inverse = ring.unit_inverse(ring.unit_part(&gcd));
+
+    Some((
+        ring.normal_form(gcd),
+        ring.mul(s, inverse.clone()),
+        ring.mul(t, inverse),
+    ))
+}
+
+/// Extended euclidean algorithm, see [extended_euclidean]
+///
+/// This fails with [Error::AllZero] iff `a` and `b` are 0 and with [Error::Overflow] if an
+/// intermediate result doesn't fit into the elements of the ring. Rings whose arithmetic can
+/// overflow (see [Ring::can_overflow]) always use [try_classical_extended_euclidean].
+pub fn try_extended_euclidean<E: EuclideanRing>(
+    ring: E,
+    a: E::Element,
+    b: E::Element,
+) -> Result<(E::Element, E::Element, E::Element)> {
+    if !ring.can_overflow() {
+        return extended_euclidean(ring, a, b).ok_or(Error::AllZero);
+    }
+
+    let (gcd, s, t) = try_classical_extended_euclidean(ring, a, b)?;
+    let inverse = ring.unit_inverse(ring.unit_part(&gcd));
+    let scale = |elem| {
+        ring.checked_mul(elem, inverse.clone())
+            .ok_or(Error::Overflow)
+    };
+
+    Ok((scale(gcd)?, scale(s)?, scale(t)?))
+}
+
+/// Classical extended euclidean algorithm that performs one euclidean division per step
+///
+/// See [extended_euclidean] for the result, but note that `gcd` is not normalized. Panics if an
+/// intermediate result doesn't fit into the elements of the ring, see
+/// [try_classical_extended_euclidean].
+pub fn classical_extended_euclidean<E: EuclideanRing>(
+    ring: E,
+    a: E::Element,
+    b: E::Element,
+) -> Option<(E::Element, E::Element, E::Element)> {
+    match try_classical_extended_euclidean(ring, a, b) {
+        Err(Error::AllZero) => None,
+        result => Some(result.expect("the coefficients fit into the elements of the ring")),
+    }
+}
+
+/// Classical extended euclidean algorithm, see [classical_extended_euclidean]
+///
+/// This fails with [Error::AllZero] iff `a` and `b` are 0 and with [Error::Overflow] if an
+/// intermediate result doesn't fit into the elements of the ring.
+pub fn try_classical_extended_euclidean<E: EuclideanRing>(
+    ring: E,
+    a: E::Element,
+    b: E::Element,
+) -> Result<(E::Element, E::Element, E::Element)> {
+    if a == ring.zero() && b == ring.zero() {
+        return Err(Error::AllZero);
+    }
+
+    if b == ring.zero() {
+        return Ok((a, ring.one(), ring.zero()));
+    }
+
+    let (_, rem) = E::try_euclidean_division(a.clone(), b.clone())?;
+    if rem == ring.zero() {
+        return Ok((b, ring.zero(), ring.one()));
+    }
+
+    // `lhs - q * rhs` for the Bézout coefficients
+    let step = |lhs: E::Element, q: E::Element, rhs: E::Element| {
+        ring.checked_mul(q, rhs)
+            .and_then(|product| ring.checked_sub(lhs, product))
+            .ok_or(Error::Overflow)
+    };
+
+    let (mut x, mut y) = (a, b);
+    let (mut s1, mut s2) = (ring.one(), ring.zero());
+    let (mut t1, mut t2) = (ring.zero(), ring.one());
+    let (mut s, mut t) = (ring.zero(), ring.zero());
+
+    loop {
+        let (q, r) = E::try_euclidean_division(x.clone(), y.clone())?;
+
+        if r == ring.zero() {
+            break;
+        }
+
+        s = step(s1, q.clone(), s2.clone())?;
+        t = step(t1, q, t2.clone())?;
+        s1 = s2;
+        s2 = s.clone();
+        t1 = t2;
+        t2 = t.clone();
+
+        x = y;
+        y = r;
+    }
+
+    Ok((y, s, t))
+}
+
+/// Truncated extended euclidean algorithm
+///
+/// This walks through the same remainder sequence as [extended_euclidean] but stops at the first
+/// remainder `r` whose euclidean function is smaller than `bound`. A zero remainder always
+/// satisfies the bound.
+///
+/// This will compute `(r, s, t)` so that the equation `(s * a) + (t * b) = r` holds.
+pub fn partial_extended_euclidean<E: EuclideanRing>(
+    ring: E,
+    a: E::Element,
+    b: E::Element,
+    bound: usize,
+) -> (E::Element, E::Element, E::Element) {
+    let below_bound =
+        |elem: &E::Element| E::euclidean_function(elem.clone()).is_none_or(|f| f < bound);
+
+    if below_bound(&a) {
+        return (a, ring.one(), ring.zero());
+    }
+
+    let (mut r0, mut s0, mut t0) = (a, ring.one(), ring.zero());
+    let (mut r1, mut s1, mut t1) = (b, ring.zero(), ring.one());
+
+    while r1 != ring.zero() && !below_bound(&r1) {
+        let (q, r) = E::euclidean_division(r0, r1.clone()).expect("r1 is non-zero");
+        let s = ring.sub(s0, ring.mul(q.clone(), s1.clone()));
+        let t = ring.sub(t0, ring.mul(q, t1.clone()));
+
+        (r0, s0, t0) = (r1, s1, t1);
+        (r1, s1, t1) = (r, s, t);
+    }
+
+    (r1, s1, t1)
+}
+
+/// Extended euclidean algorithm for many elements
+///
+/// This will compute an `Option<(gcd, coefficients)>` so that `gcd` is the greatest common divisor
+/// of all `elems` and the equation `Σ coefficients[i] * elems[i] = gcd` holds.
+///
+/// This will be `None` iff all `elems` are 0 (or there are none). Panics if an intermediate
+/// result doesn't fit into the elements of the ring, see [try_extended_euclidean_many].
+pub fn extended_euclidean_many<E: EuclideanRing>(
+    ring: E,
+    elems: impl IntoIterator<Item = E::Element>,
+) -> Option<(E::Element, Vec<E::Element>)> {
+    match try_extended_euclidean_many(ring, elems) {
+        Err(Error::AllZero) => None,
+        result => Some(result.expect("the coefficients fit into the elements of the ring")),
+    }
+}
+
+/// Extended euclidean algorithm for many elements, see [extended_euclidean_many]
+///
+/// This fails with [Error::AllZero] iff all `elems` are 0 (or there are none) and with
+/// [Error::Overflow] if an intermediate result doesn't fit, see [try_extended_euclidean].
+pub fn try_extended_euclidean_many<E: EuclideanRing>(
+    ring: E,
+    elems: impl IntoIterator<Item = E::Element>,
+) -> Result<(E::Element, Vec<E::Element>)> {
+    let mut gcd = ring.zero();
+    let mut coefficients: Vec<E::Element> = Vec::new();
+
+    for elem in elems {
+        match try_extended_euclidean(ring, gcd.clone(), elem) {
+            Ok((g, s, t)) => {
+                for c in &mut coefficients {
+                    *c = ring
+                        .checked_mul(c.clone(), s.clone())
+                        .ok_or(Error::Overflow)?;
+                }
+                coefficients.push(t);
+                gcd = g;
+            }
+            Err(Error::AllZero) => coefficients.push(ring.zero()),
+            Err(err) => return Err(err),
+        }
+    }
+
+    if gcd == ring.zero() {
+        return Err(Error::AllZero);
+    }
+
+    Ok((gcd, coefficients))
+}
+
+/// Greatest common divisor of many elements
+///
+/// This will be `None` iff all `elems` are 0 (or there are none).
+pub fn gcd_many<E: EuclideanRing>(
+    ring: E,
+    elems: impl IntoIterator<Item = E::Element>,
+) -> Option<E::Element> {
+    let gcd = elems.into_iter().fold(ring.zero(), |gcd, elem| {
+        extended_euclidean(ring, gcd, elem).map_or(ring.zero(), |(g, _, _)| g)
+    });
+
+    (gcd != ring.zero()).then_some(gcd)
+}
+
+/// Least common multiple of two elements in normal form
+///
+/// This will be 0 iff one of `a` and `b` is 0. Panics if the result doesn't fit into the elements
+/// of the ring, see [try_lcm].
+pub fn lcm<E: EuclideanRing>(ring: E, a: E::Element, b: E::Element) -> E::Element {
+    try_lcm(ring, a, b).expect("the LCM fits into the elements of the ring")
+}
+
+/// Least common multiple of two elements, see [lcm]
+///
+/// This fails with [Error::Overflow] if the result doesn't fit into the elements of the ring,
+/// e.g. if the product of two large coprime integers overflows.
+pub fn try_lcm<E: EuclideanRing>(ring: E, a: E::Element, b: E::Element) -> Result<E::Element> {
+    if a == ring.zero() || b == ring.zero() {
+        return Ok(ring.zero());
+    }
+
+    let (gcd, _, _) = try_extended_euclidean(ring, a.clone(), b.clone())?;
+    let (quotient, _) = E::try_euclidean_division(b, gcd)?;
+    let product = ring.checked_mul(a, quotient).ok_or(Error::Overflow)?;
+
+    // The normal form is the product with the inverse unit, which can overflow as well
+    let unit_inverse = ring.unit_inverse(ring.unit_part(&product));
+    ring.checked_mul(product, unit_inverse)
+        .ok_or(Error::Overflow)
+}
+
+/// Least common multiple of many elements in normal form
+///
+/// This will be 1 if there are no `elems` and 0 iff one of them is 0. Panics if the result
+/// doesn't fit into the elements of the ring, see [try_lcm_many].
+pub fn lcm_many<E: EuclideanRing>(
+    ring: E,
+    elems: impl IntoIterator<Item = E::Element>,
+) -> E::Element {
+    try_lcm_many(ring, elems).expect("the LCM fits into the elements of the ring")
+}
+
+/// Least common multiple of many elements, see [lcm_many]
+///
+/// This fails with [Error::Overflow] if an intermediate result doesn't fit, see [try_lcm].
+pub fn try_lcm_many<E: EuclideanRing>(
+    ring: E,
+    elems: impl IntoIterator<Item = E::Element>,
+) -> Result<E::Element> {
+    elems
+        .into_iter()
+        .try_fold(ring.one(), |acc, elem| try_lcm(ring, acc, elem))
+}
+
+/// Extended euclidean algorithm for integers
+///
+/// This is similar to [extended_euclidean] for [Integers] but returns the GCD as unsigned integer.
+pub fn extended_euclidean_int(a: isize, b: isize) -> Option<(usize, isize, isize)> {
+    extended_euclidean(Integers, a, b).map(|(gcd, s, t)| (gcd.unsigned_abs(), s, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::Reals;
+
+    #[test]
+    fn eea_48_neg30() {
+        let (gcd, s, t) = extended_euclidean_int(48, -30).unwrap();
+        assert_eq!((gcd, s, t), (6, 2, 3));
+    }
+
+    #[test]
+    fn many_integers() {
+        let elems = [12, 0, 18, -8];
+        let (gcd, coefficients) = extended_euclidean_many(Integers, elems).unwrap();
+
+        assert_eq!(gcd, 2);
+        assert_eq!(gcd_many(Integers, elems), Some(gcd));
+        assert_eq!(
+            elems
+                .iter()
+                .zip(&coefficients)
+                .map(|(a, s)| a * s)
+                .sum::<isize>(),
+            gcd
+        );
+
+        assert_eq!(lcm_many(Integers, [4, -6, 10]), 60);
+        assert_eq!(lcm_many(Integers, [4, 0, 10]), 0);
+        assert_eq!(
+            try_lcm(Integers, isize::MAX, isize::MAX - 1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(try_lcm(Integers, isize::MIN, 1), Err(Error::Overflow));
+        assert_eq!(gcd_many(Integers, [0, 0]), None);
+    }
+
+    #[test]
+    fn monic_polynomial_gcd() {
+        let poly_ring = PolynomialRing::new(Reals);
+        let a = Polynomial::new(Reals, vec![6.0, 8.0, 2.0]);
+        let b = Polynomial::new(Reals, vec![-2.0, 0.0, 2.0]);
+
+        let (gcd, s, t) = extended_euclidean(poly_ring, a.clone(), b.clone()).unwrap();
+        assert_eq!(gcd, Polynomial::new(Reals, vec![1.0, 1.0]));
+        assert_eq!(s * a + t * b, gcd);
+    }
+}
@@ -14,6 +14,13 @@
 //! assert_eq!(f + g, Polynomial::new(z_mod_5, vec![3, 1, 1]));
 //! ```
 
+pub mod dynamic;
+mod error;
 pub mod euclid;
 pub mod polynomial;
+pub mod primes;
+pub mod reconstruction;
+pub mod recurrence;
 pub mod ring;
+
+pub use error::{Error, Result};
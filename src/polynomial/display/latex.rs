@@ -0,0 +1,264 @@
+//! Rendering polynomials and results as LaTeX
+
+use std::fmt;
+
+use super::{render_coefficient, DisplayOptions, DisplayRing, Format, Separator};
+use crate::polynomial::Polynomial;
+
+impl<R> Polynomial<R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    /// Render the polynomial as LaTeX, e.g. `3x^{2} - x + 1`
+    pub fn to_latex(&self, options: &DisplayOptions) -> String {
+        self.fold_display_parts(
+            options,
+            String::new(),
+            |s, coeff, parenthesized| {
+                s.push_str(&render_coefficient(
+                    self.ring,
+                    &coeff,
+                    parenthesized,
+                    Format::Latex,
+                    options,
+                ));
+            },
+            |s, exp| {
+                push_separated(s, &variable(&options.variable));
+                if let Some(exp) = exp {
+                    s.push_str(&format!("^{{{exp}}}"));
+                }
+            },
+            |s, sep| {
+                s.push_str(match sep {
+                    Separator::LeadingMinus => "-",
+                    Separator::Plus => " + ",
+                    Separator::Minus => " - ",
+                });
+            },
+        )
+    }
+}
+
+/// The polynomial ring over `ring` in LaTeX, e.g. `\mathbb{Z}/5\mathbb{Z}[x]`
+pub fn polynomial_ring<R: DisplayRing>(ring: &R, options: &DisplayOptions) -> String {
+    format!("{}[{}]", ring.latex_name(), variable(&options.variable))
+}
+
+/// The name of a variable in LaTeX math mode
+///
+/// Greek letters become commands like `\alpha`, the special characters of LaTeX are escaped and
+/// other non-ASCII characters are put into `\text{...}`, e.g. `x_1` becomes `x\_1`.
+pub fn variable(name: &str) -> String {
+    let mut latex = String::new();
+    for c in name.chars() {
+        let escaped = match c {
+            '_' | '&' | '%' | '$' | '#' | '{' | '}' => format!(r"\{c}"),
+            '\\' => String::from(r"\backslash"),
+            '^' => String::from(r"\text{\^{}}"),
+            '~' => String::from(r"\text{\~{}}"),
+            _ if c.is_ascii() => c.to_string(),
+            _ => match greek_letter(c) {
+                Some(command) => format!(r"\{command}"),
+                None => format!(r"\text{{{c}}}"),
+            },
+        };
+        push_separated(&mut latex, &escaped);
+    }
+
+    latex
+}
+
+/// The LaTeX command of a Greek letter without the backslash, e.g. `alpha` for `α`
+fn greek_letter(c: char) -> Option<&'static str> {
+    let command = match c {
+        'α' => "alpha",
+        'β' => "beta",
+        'γ' => "gamma",
+        'δ' => "delta",
+        'ε' => "epsilon",
+        'ζ' => "zeta",
+        'η' => "eta",
+        'θ' => "theta",
+        'ι' => "iota",
+        'κ' => "kappa",
+        'λ' => "lambda",
+        'μ' => "mu",
+        'ν' => "nu",
+        'ξ' => "xi",
+        'π' => "pi",
+        'ρ' => "rho",
+        'σ' => "sigma",
+        'ς' => "varsigma",
+        'τ' => "tau",
+        'υ' => "upsilon",
+        'φ' => "phi",
+        'χ' => "chi",
+        'ψ' => "psi",
+        'ω' => "omega",
+        'Γ' => "Gamma",
+        'Δ' => "Delta",
+        'Θ' => "Theta",
+        'Λ' => "Lambda",
+        'Ξ' => "Xi",
+        'Π' => "Pi",
+        'Σ' => "Sigma",
+        'Υ' => "Upsilon",
+        'Φ' => "Phi",
+        'Ψ' => "Psi",
+        'Ω' => "Omega",
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+/// Append `latex` to `s` with a space in between if `s` ends with a command like `\alpha` that
+/// would otherwise swallow the letters at the start of `latex`
+fn push_separated(s: &mut String, latex: &str) {
+    let letters = s.len() - s.trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+    let ends_with_command = letters > 0 && s[..s.len() - letters].ends_with('\\');
+
+    if ends_with_command && latex.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        s.push(' ');
+    }
+    s.push_str(latex);
+}
+
+/// The result of a polynomial division in LaTeX, e.g. `x^{2} + 1 = x \cdot x + 1`
+///
+/// The remainder `r` is left out if it is zero.
+pub fn division<R>(
+    lhs: &Polynomial<R>,
+    rhs: &Polynomial<R>,
+    q: &Polynomial<R>,
+    r: &Polynomial<R>,
+    options: &DisplayOptions,
+) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let mut latex = format!(
+        r"{} = {} \cdot {}",
+        lhs.to_latex(options),
+        factor(q, options),
+        factor(rhs, options)
+    );
+
+    if !r.is_zero() {
+        let r = r.to_latex(options);
+        match r.strip_prefix('-') {
+            Some(negated) => latex.push_str(&format!(" - {negated}")),
+            None => latex.push_str(&format!(" + {r}")),
+        }
+    }
+
+    latex
+}
+
+/// The GCD of `operands` with its Bézout coefficients in LaTeX
+///
+/// E.g. `\gcd\left(4, 6\right) = 2 = \left(-1\right) \cdot 4 + 1 \cdot 6`.
+pub fn bezout<R>(
+    gcd: &Polynomial<R>,
+    operands: &[Polynomial<R>],
+    coefficients: &[Polynomial<R>],
+    options: &DisplayOptions,
+) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let operand_list: Vec<String> = operands.iter().map(|a| a.to_latex(options)).collect();
+    let combination: Vec<String> = coefficients
+        .iter()
+        .zip(operands)
+        .map(|(s, a)| format!(r"{} \cdot {}", factor(s, options), factor(a, options)))
+        .collect();
+
+    format!(
+        r"\gcd\left({}\right) = {} = {}",
+        operand_list.join(", "),
+        gcd.to_latex(options),
+        combination.join(" + ")
+    )
+}
+
+/// Render `poly` as a factor of a product, with parentheses if it is a sum or negative
+fn factor<R>(poly: &Polynomial<R>, options: &DisplayOptions) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let latex = poly.to_latex(options);
+
+    if poly.needs_parentheses(options) {
+        format!(r"\left({latex}\right)")
+    } else {
+        latex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::parse::ParseOptions;
+    use crate::ring::{GaloisField, Integers, IntegersModuloN, Reals};
+
+    #[test]
+    fn polynomials_and_results() {
+        let options = DisplayOptions::default();
+
+        let poly = Polynomial::new(Reals, vec![1.0, -0.5, 3.0]);
+        assert_eq!(poly.to_latex(&options), "3x^{2} - 0.5x + 1");
+
+        let ring = IntegersModuloN::new(5);
+        assert_eq!(
+            polynomial_ring(&ring, &options),
+            r"\mathbb{Z}/5\mathbb{Z}[x]"
+        );
+
+        let lhs = Polynomial::new(Integers, vec![-3, 0, 1]);
+        let rhs = Polynomial::new(Integers, vec![1, 1]);
+        let q = Polynomial::new(Integers, vec![-1, 1]);
+        let r = Polynomial::new(Integers, vec![-2]);
+        assert_eq!(
+            division(&lhs, &rhs, &q, &r, &options),
+            r"x^{2} - 3 = \left(x - 1\right) \cdot \left(x + 1\right) - 2"
+        );
+
+        let int = |n| Polynomial::constant(Integers, n);
+        assert_eq!(
+            bezout(&int(2), &[int(4), int(6)], &[int(-1), int(1)], &options),
+            r"\gcd\left(4, 6\right) = 2 = \left(-1\right) \cdot 4 + 1 \cdot 6"
+        );
+    }
+
+    #[test]
+    fn escaped_variables() {
+        assert_eq!(variable("x_1"), r"x\_1");
+        assert_eq!(variable("λμ"), r"\lambda\mu");
+        assert_eq!(variable("αb"), r"\alpha b");
+        assert_eq!(variable("ä#"), r"\text{ä}\#");
+
+        let options = DisplayOptions {
+            variable: String::from("β"),
+            ..Default::default()
+        };
+        let field = GaloisField::new(2, 2).unwrap();
+        let parse_options = ParseOptions {
+            variable: options.variable.clone(),
+        };
+        let poly = Polynomial::parse_with(field, "αβ^2 + (α + 1)β", &parse_options).unwrap();
+        assert_eq!(
+            poly.to_latex(&options),
+            r"\alpha\beta^{2} + \left(\alpha + 1\right)\beta"
+        );
+        assert_eq!(
+            polynomial_ring(&field, &options),
+            r"\mathrm{GF}(2^{2})[\beta]"
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Rendering polynomials and results as MathML
+//!
+//! Functions that return a `<math>` element can be embedded into HTML directly. The fragments
+//! (e.g. from [DisplayRing::mathml_name] or [integers_modulo]) can be combined and wrapped with
+//! [math].
+
+use std::fmt;
+
+use super::{render_coefficient, DisplayOptions, DisplayPart, DisplayRing, Format, Separator};
+use crate::polynomial::Polynomial;
+
+/// The double-struck R of the real numbers
+pub const LETTER_R: &str = "ℝ";
+
+/// The double-struck Z of the integers
+pub const LETTER_Z: &str = "ℤ";
+
+/// The double-struck Q of the rationals
+pub const LETTER_Q: &str = "ℚ";
+
+impl<R> Polynomial<R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    /// Render the polynomial as a `<math>` element
+    pub fn to_mathml(&self, options: &DisplayOptions) -> String {
+        math(&polynomial(self, options))
+    }
+}
+
+/// Wrap a MathML fragment into a `<math>` element
+pub fn math(fragment: &str) -> String {
+    format!("<math>{fragment}</math>")
+}
+
+/// The fragment `ℤ/nℤ`, where `n` is a MathML fragment like `<mn>5</mn>` or `<mi>p</mi>`
+pub fn integers_modulo(n: &str) -> String {
+    format!("<mrow><mi>{LETTER_Z}</mi><mo>/</mo>{n}<mi>{LETTER_Z}</mi></mrow>")
+}
+
+/// The fragment `ring[x]` of the polynomial ring over the MathML fragment `ring`
+pub fn adjoin(ring: &str, variable: &str) -> String {
+    format!(
+        "<mrow>{ring}<mo>[</mo><mi>{}</mi><mo>]</mo></mrow>",
+        escape(variable)
+    )
+}
+
+/// The polynomial ring over `ring` as a `<math>` element, e.g. `ℤ/5ℤ[x]`
+pub fn polynomial_ring<R: DisplayRing>(ring: &R, options: &DisplayOptions) -> String {
+    math(&adjoin(&ring.mathml_name(), &options.variable))
+}
+
+/// The result of a polynomial division as a `<math>` element, e.g. `x^2 + 1 = x · x + 1`
+///
+/// The remainder `r` is left out if it is zero.
+pub fn division<R>(
+    lhs: &Polynomial<R>,
+    rhs: &Polynomial<R>,
+    q: &Polynomial<R>,
+    r: &Polynomial<R>,
+    options: &DisplayOptions,
+) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let mut mathml = format!(
+        "{}<mo>=</mo>{}<mo>·</mo>{}",
+        polynomial(lhs, options),
+        factor(q, options),
+        factor(rhs, options)
+    );
+
+    if !r.is_zero() {
+        let parts = DisplayPart::get_parts(r, options);
+        if parts[0].negative {
+            mathml.push_str("<mo>-</mo>");
+            mathml.push_str(&factor(&-r.clone(), options));
+        } else {
+            mathml.push_str("<mo>+</mo>");
+            mathml.push_str(&polynomial(r, options));
+        }
+    }
+
+    math(&mathml)
+}
+
+/// The GCD of `operands` with its Bézout coefficients as a `<math>` element
+///
+/// E.g. `gcd(4, 6) = 2 = (-1) · 4 + 1 · 6`.
+pub fn bezout<R>(
+    gcd: &Polynomial<R>,
+    operands: &[Polynomial<R>],
+    coefficients: &[Polynomial<R>],
+    options: &DisplayOptions,
+) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let operand_list: Vec<String> = operands.iter().map(|a| polynomial(a, options)).collect();
+    let combination: Vec<String> = coefficients
+        .iter()
+        .zip(operands)
+        .map(|(s, a)| format!("{}<mo>·</mo>{}", factor(s, options), factor(a, options)))
+        .collect();
+
+    math(&format!(
+        "<mi>gcd</mi><mrow><mo>(</mo>{}<mo>)</mo></mrow><mo>=</mo>{}<mo>=</mo>{}",
+        operand_list.join("<mo>,</mo>"),
+        polynomial(gcd, options),
+        combination.join("<mo>+</mo>")
+    ))
+}
+
+/// Render `poly` as an `<mrow>` fragment
+pub(crate) fn polynomial<R>(poly: &Polynomial<R>, options: &DisplayOptions) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let variable = format!("<mi>{}</mi>", escape(&options.variable));
+
+    let content = poly.fold_display_parts(
+        options,
+        String::new(),
+        |s, coeff, parenthesized| {
+            s.push_str(&render_coefficient(
+                poly.ring,
+                &coeff,
+                parenthesized,
+                Format::MathMl,
+                options,
+            ));
+        },
+        |s, exp| match exp {
+            Some(exp) => s.push_str(&format!("<msup>{variable}<mn>{exp}</mn></msup>")),
+            None => s.push_str(&variable),
+        },
+        |s, sep| {
+            s.push_str(match sep {
+                Separator::LeadingMinus | Separator::Minus => "<mo>-</mo>",
+                Separator::Plus => "<mo>+</mo>",
+            });
+        },
+    );
+
+    format!("<mrow>{content}</mrow>")
+}
+
+/// Render `poly` as a factor of a product, with parentheses if it is a sum or negative
+fn factor<R>(poly: &Polynomial<R>, options: &DisplayOptions) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let mathml = polynomial(poly, options);
+
+    if poly.needs_parentheses(options) {
+        format!("<mrow><mo>(</mo>{mathml}<mo>)</mo></mrow>")
+    } else {
+        mathml
+    }
+}
+
+/// Escape the characters of `text` that have a meaning in XML
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::{Integers, IntegersModuloN};
+
+    #[test]
+    fn polynomials_and_rings() {
+        let options = DisplayOptions::default();
+
+        let poly = Polynomial::new(Integers, vec![1, -1, 3]);
+        assert_eq!(
+            poly.to_mathml(&options),
+            "<math><mrow><mn>3</mn><msup><mi>x</mi><mn>2</mn></msup>\
+             <mo>-</mo><mi>x</mi><mo>+</mo><mn>1</mn></mrow></math>"
+        );
+
+        let ring = IntegersModuloN::new(5);
+        assert_eq!(
+            polynomial_ring(&ring, &options),
+            "<math><mrow><mrow><mi>ℤ</mi><mo>/</mo><mn>5</mn><mi>ℤ</mi></mrow>\
+             <mo>[</mo><mi>x</mi><mo>]</mo></mrow></math>"
+        );
+
+        let options = DisplayOptions {
+            variable: String::from("<y>"),
+            ..Default::default()
+        };
+        let poly = Polynomial::new(Integers, vec![0, 1]);
+        assert_eq!(
+            poly.to_mathml(&options),
+            "<math><mrow><mi>&lt;y&gt;</mi></mrow></math>"
+        );
+
+        let r = Polynomial::new(Integers, vec![-1, -1]);
+        let lhs = Polynomial::new(Integers, vec![-1, -1, 1]);
+        let rhs = Polynomial::single(Integers, 1, 1);
+        assert!(division(&lhs, &rhs, &rhs, &r, &options).ends_with(
+            "<mo>-</mo><mrow><mo>(</mo><mrow><mi>&lt;y&gt;</mi><mo>+</mo><mn>1</mn></mrow>\
+             <mo>)</mo></mrow></math>"
+        ));
+    }
+}
@@ -0,0 +1,871 @@
+//! Displaying polynomials
+
+pub mod latex;
+pub mod mathml;
+pub mod unicode;
+
+use std::fmt;
+
+use crate::ring::{
+    GaloisField, GaussianIntegers, Integers, IntegersModuloAny, MontgomeryModuloP, PolynomialRing,
+    QuadraticField, Rational, Rationals, Reals, Ring,
+};
+
+use super::Polynomial;
+
+/// A ring where polynomials can be displayed
+pub trait DisplayRing: Ring {
+    /// The symbol of the ring in LaTeX, e.g. `\mathbb{Z}`
+    fn latex_name(&self) -> String;
+
+    /// The symbol of the ring in MathML, e.g. `<mi>ℤ</mi>`
+    fn mathml_name(&self) -> String;
+
+    /// The symbol of the ring in Unicode, e.g. `ℤ`
+    fn unicode_name(&self) -> String;
+
+    /// Whether `elem` is displayed as the negation of another element
+    ///
+    /// Negative coefficients are displayed by subtracting their negation, e.g. `x - 1` instead
+    /// of `x + -1`.
+    fn is_negative(&self, elem: &Self::Element, options: &DisplayOptions) -> bool {
+        let _ = (elem, options);
+        false
+    }
+
+    /// Whether `elem` is displayed as a sum like `1 + i`
+    ///
+    /// Such coefficients are put in parentheses if they are multiplied by the variable or
+    /// subtracted, e.g. `(1 + i)x` or `x - (1 + i)`. A compound constant polynomial is displayed
+    /// as it is, e.g. `-1 + i` instead of `-(1 - i)`.
+    fn is_compound(&self, elem: &Self::Element) -> bool {
+        let _ = elem;
+        false
+    }
+
+    /// Render a coefficient (which is never negative unless it is compound) in the given [Format]
+    ///
+    /// The `options` are those of the polynomial that the coefficient belongs to. Compound
+    /// elements are rendered without parentheses, see [Self::is_compound].
+    fn render_elem(&self, elem: &Self::Element, format: Format, options: &DisplayOptions) -> String
+    where
+        Self::Element: fmt::Display,
+    {
+        let _ = options;
+        match format {
+            Format::MathMl => format!("<mn>{}</mn>", mathml::escape(&elem.to_string())),
+            Format::Plain | Format::Unicode | Format::Latex => elem.to_string(),
+        }
+    }
+}
+
+/// The formats that polynomials can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// See [Polynomial::display]
+    Plain,
+
+    /// See [Polynomial::to_unicode]
+    Unicode,
+
+    /// See [Polynomial::to_latex]
+    Latex,
+
+    /// See [Polynomial::to_mathml]
+    MathMl,
+}
+
+impl DisplayRing for Reals {
+    fn latex_name(&self) -> String {
+        String::from(r"\mathbb{R}")
+    }
+
+    fn mathml_name(&self) -> String {
+        format!("<mi>{}</mi>", mathml::LETTER_R)
+    }
+
+    fn unicode_name(&self) -> String {
+        String::from(mathml::LETTER_R)
+    }
+
+    fn is_negative(&self, elem: &Self::Element, _options: &DisplayOptions) -> bool {
+        *elem < 0.0
+    }
+}
+
+impl DisplayRing for Integers {
+    fn latex_name(&self) -> String {
+        String::from(r"\mathbb{Z}")
+    }
+
+    fn mathml_name(&self) -> String {
+        format!("<mi>{}</mi>", mathml::LETTER_Z)
+    }
+
+    fn unicode_name(&self) -> String {
+        String::from(mathml::LETTER_Z)
+    }
+
+    fn is_negative(&self, elem: &Self::Element, _options: &DisplayOptions) -> bool {
+        *elem < 0
+    }
+}
+
+/// Fractions are rendered as `\frac` in LaTeX and as `<mfrac>` in MathML, e.g. `1/2x`
+impl DisplayRing for Rationals {
+    fn latex_name(&self) -> String {
+        String::from(r"\mathbb{Q}")
+    }
+
+    fn mathml_name(&self) -> String {
+        format!("<mi>{}</mi>", mathml::LETTER_Q)
+    }
+
+    fn unicode_name(&self) -> String {
+        String::from(mathml::LETTER_Q)
+    }
+
+    fn is_negative(&self, elem: &Self::Element, _options: &DisplayOptions) -> bool {
+        elem.is_negative()
+    }
+
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        _options: &DisplayOptions,
+    ) -> String {
+        render_rational(*elem, format)
+    }
+}
+
+/// Gaussian integers with a real and an imaginary part are compound, e.g. `(1 + i)x`
+impl DisplayRing for GaussianIntegers {
+    fn latex_name(&self) -> String {
+        String::from(r"\mathbb{Z}[i]")
+    }
+
+    fn mathml_name(&self) -> String {
+        mathml::adjoin(&Integers.mathml_name(), "i")
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("{}[i]", mathml::LETTER_Z)
+    }
+
+    /// An element is negative if its real part is, or if it is imaginary with a negative part
+    fn is_negative(&self, elem: &Self::Element, _options: &DisplayOptions) -> bool {
+        elem.re < 0 || elem.re == 0 && elem.im < 0
+    }
+
+    fn is_compound(&self, elem: &Self::Element) -> bool {
+        elem.re != 0 && elem.im != 0
+    }
+
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        options: &DisplayOptions,
+    ) -> String {
+        let imaginary = match elem.im.unsigned_abs() {
+            1 if format == Format::MathMl => String::from("<mi>i</mi>"),
+            1 => String::from("i"),
+            im if format == Format::MathMl => format!("<mn>{im}</mn><mi>i</mi>"),
+            im => format!("{im}i"),
+        };
+        let real = match Integers.render_elem(&elem.re.abs(), format, options) {
+            real if elem.re < 0 => format!("{}{real}", minus(format)),
+            real => real,
+        };
+
+        if elem.im == 0 {
+            return real;
+        }
+        if elem.re == 0 {
+            return imaginary;
+        }
+
+        let sign = match (format, elem.im < 0) {
+            (Format::MathMl, false) => "<mo>+</mo>",
+            (Format::MathMl, true) => "<mo>-</mo>",
+            (Format::Unicode, true) => " − ",
+            (_, true) => " - ",
+            (_, false) => " + ",
+        };
+
+        format!("{real}{sign}{imaginary}")
+    }
+}
+
+/// Elements with a `√d` part are compound, e.g. `(1 + √2)x` or `(2√2)x`
+impl DisplayRing for QuadraticField {
+    fn latex_name(&self) -> String {
+        format!(r"\mathbb{{Q}}(\sqrt{{{}}})", self.d())
+    }
+
+    fn mathml_name(&self) -> String {
+        format!(
+            "<mrow><mi>{}</mi><mo>(</mo>{}<mo>)</mo></mrow>",
+            mathml::LETTER_Q,
+            self.render_sqrt_d(Format::MathMl)
+        )
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("{}(√{})", mathml::LETTER_Q, self.d())
+    }
+
+    /// An element is negative if its rational part is, or if it is a negative multiple of `√d`
+    fn is_negative(&self, elem: &Self::Element, _options: &DisplayOptions) -> bool {
+        elem.a.is_negative() || elem.a.is_zero() && elem.b.is_negative()
+    }
+
+    fn is_compound(&self, elem: &Self::Element) -> bool {
+        !elem.b.is_zero()
+    }
+
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        _options: &DisplayOptions,
+    ) -> String {
+        let rational = match render_rational(elem.a.abs(), format) {
+            rational if elem.a.is_negative() => format!("{}{rational}", minus(format)),
+            rational => rational,
+        };
+        if elem.b.is_zero() {
+            return rational;
+        }
+
+        // A fraction is put in parentheses so that `(3/2)√2` can't be read as `3/(2√2)`
+        let root = match elem.b.abs() {
+            b if b == Rational::one() => self.render_sqrt_d(format),
+            b if !b.is_integer() && matches!(format, Format::Plain | Format::Unicode) => {
+                format!("({b}){}", self.render_sqrt_d(format))
+            }
+            b => format!(
+                "{}{}",
+                render_rational(b, format),
+                self.render_sqrt_d(format)
+            ),
+        };
+
+        let sign = match (format, elem.b.is_negative()) {
+            (Format::MathMl, false) => "<mo>+</mo>",
+            (Format::MathMl, true) => "<mo>-</mo>",
+            (Format::Unicode, true) => " − ",
+            (_, true) => " - ",
+            (_, false) => " + ",
+        };
+        if elem.a.is_zero() {
+            root
+        } else {
+            format!("{rational}{sign}{root}")
+        }
+    }
+}
+
+impl QuadraticField {
+    /// The root `√d`, the same as [QuadraticNumber](crate::ring::QuadraticNumber)'s `Display`
+    fn render_sqrt_d(&self, format: Format) -> String {
+        let d = self.d();
+        match format {
+            Format::Plain | Format::Unicode => format!("√{d}"),
+            Format::Latex => format!(r"\sqrt{{{d}}}"),
+            Format::MathMl => format!("<msqrt><mn>{d}</mn></msqrt>"),
+        }
+    }
+}
+
+/// The minus sign in front of a negative part of a compound element
+fn minus(format: Format) -> &'static str {
+    match format {
+        Format::Plain | Format::Latex => "-",
+        Format::Unicode => "−",
+        Format::MathMl => "<mo>-</mo>",
+    }
+}
+
+/// A non-negative fraction like `3/2`
+fn render_rational(r: Rational, format: Format) -> String {
+    let (num, den) = (r.numer(), r.denom());
+    match (format, r.is_integer()) {
+        (Format::MathMl, true) => format!("<mn>{num}</mn>"),
+        (Format::MathMl, false) => format!("<mfrac><mn>{num}</mn><mn>{den}</mn></mfrac>"),
+        (Format::Latex, false) => format!(r"\frac{{{num}}}{{{den}}}"),
+        (_, _) => r.to_string(),
+    }
+}
+
+/// Polynomials as coefficients are displayed in the variable of the ring, e.g. `(y + 1)x^2`
+impl<R> DisplayRing for PolynomialRing<R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    fn latex_name(&self) -> String {
+        format!(
+            "{}[{}]",
+            self.base_ring().latex_name(),
+            latex::variable(self.variable())
+        )
+    }
+
+    fn mathml_name(&self) -> String {
+        mathml::adjoin(&self.base_ring().mathml_name(), self.variable())
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("{}[{}]", self.base_ring().unicode_name(), self.variable())
+    }
+
+    /// A polynomial is negative if its leading coefficient is
+    fn is_negative(&self, elem: &Self::Element, options: &DisplayOptions) -> bool {
+        !elem.is_zero() && self.base_ring().is_negative(&elem.lc(), options)
+    }
+
+    /// Non-constant polynomials are put in parentheses
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        options: &DisplayOptions,
+    ) -> String {
+        let options = DisplayOptions {
+            variable: self.variable().to_string(),
+            ..options.clone()
+        };
+        let constant = elem.deg().is_none_or(|deg| deg == 0);
+
+        match (format, constant) {
+            (Format::Plain, true) => elem.display(&options).to_string(),
+            (Format::Plain, false) => format!("({})", elem.display(&options)),
+            (Format::Unicode, true) => elem.to_unicode(&options),
+            (Format::Unicode, false) => format!("({})", elem.to_unicode(&options)),
+            (Format::Latex, true) => elem.to_latex(&options),
+            (Format::Latex, false) => format!(r"\left({}\right)", elem.to_latex(&options)),
+            (Format::MathMl, true) => mathml::polynomial(elem, &options),
+            (Format::MathMl, false) => {
+                format!(
+                    "<mrow><mo>(</mo>{}<mo>)</mo></mrow>",
+                    mathml::polynomial(elem, &options)
+                )
+            }
+        }
+    }
+}
+
+impl<T: IntegersModuloAny> DisplayRing for T {
+    fn latex_name(&self) -> String {
+        format!(r"\mathbb{{Z}}/{}\mathbb{{Z}}", self.n())
+    }
+
+    fn mathml_name(&self) -> String {
+        mathml::integers_modulo(&format!("<mn>{}</mn>", self.n()))
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("{0}/{1}{0}", mathml::LETTER_Z, self.n())
+    }
+
+    fn is_negative(&self, elem: &Self::Element, options: &DisplayOptions) -> bool {
+        match options.representation {
+            Representation::Standard => false,
+            Representation::Balanced => self.residue(*elem) > self.n() / 2,
+        }
+    }
+
+    /// The elements are displayed as their residue in `0..n`, even if they are represented by
+    /// negative numbers
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        options: &DisplayOptions,
+    ) -> String {
+        let _ = options;
+        let residue = self.residue(*elem);
+        match format {
+            Format::MathMl => format!("<mn>{residue}</mn>"),
+            Format::Plain | Format::Unicode | Format::Latex => residue.to_string(),
+        }
+    }
+}
+
+/// The elements are displayed as the integers they represent
+impl DisplayRing for MontgomeryModuloP {
+    fn latex_name(&self) -> String {
+        format!(r"\mathbb{{Z}}/{}\mathbb{{Z}}", self.p())
+    }
+
+    fn mathml_name(&self) -> String {
+        mathml::integers_modulo(&format!("<mn>{}</mn>", self.p()))
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("{0}/{1}{0}", mathml::LETTER_Z, self.p())
+    }
+
+    fn is_negative(&self, elem: &Self::Element, options: &DisplayOptions) -> bool {
+        match options.representation {
+            Representation::Standard => false,
+            Representation::Balanced => self.to_int(*elem).unsigned_abs() > self.p() / 2,
+        }
+    }
+
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        options: &DisplayOptions,
+    ) -> String {
+        Integers.render_elem(&self.to_int(*elem), format, options)
+    }
+}
+
+/// The elements are displayed as polynomials in the generator `α`, e.g. `(α + 1)x^2 + α`
+///
+/// Elements with more than one term are compound.
+impl DisplayRing for GaloisField {
+    fn latex_name(&self) -> String {
+        format!(r"\mathrm{{GF}}({}^{{{}}})", self.p(), self.k())
+    }
+
+    fn mathml_name(&self) -> String {
+        format!(
+            "<mrow><mi>GF</mi><mo>(</mo><msup><mn>{}</mn><mn>{}</mn></msup><mo>)</mo></mrow>",
+            self.p(),
+            self.k()
+        )
+    }
+
+    fn unicode_name(&self) -> String {
+        format!("GF({}{})", self.p(), unicode::superscript(self.k()))
+    }
+
+    fn is_compound(&self, elem: &Self::Element) -> bool {
+        let poly = self.to_poly(*elem);
+        (0..self.k()).filter(|&deg| poly.coeff(deg) != 0).count() > 1
+    }
+
+    fn render_elem(
+        &self,
+        elem: &Self::Element,
+        format: Format,
+        options: &DisplayOptions,
+    ) -> String {
+        let poly = self.to_poly(*elem);
+        let options = DisplayOptions {
+            variable: String::from("α"),
+            ..options.clone()
+        };
+
+        match format {
+            Format::Plain => poly.display(&options).to_string(),
+            Format::Unicode => poly.to_unicode(&options),
+            Format::Latex => poly.to_latex(&options),
+            Format::MathMl => mathml::polynomial(&poly, &options),
+        }
+    }
+}
+
+/// A part of a displayable [Polynomial], useful for outputting
+///
+/// - `negative` is `true` if the part is subtracted
+/// - `coefficient` can be `None` (if it is a One) or `Some` otherwise, it is never negative
+/// - `parenthesized` is `true` if the coefficient must be put in parentheses, see
+///   [DisplayRing::is_compound]
+/// - `variable` can be `None` (degree 0), `Some(None)` (degree 1) or `Some(pot)` (degree `pot`)
+///
+/// See [Self::get_parts] for how to generate these parts from a [Polynomial].
+pub struct DisplayPart<R: DisplayRing> {
+    pub negative: bool,
+    pub coefficient: Option<R::Element>,
+    pub parenthesized: bool,
+    pub variable: Option<Option<usize>>,
+}
+
+/// What is displayed in front of a [DisplayPart]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// A minus sign in front of the first part, e.g. `-x`
+    LeadingMinus,
+
+    /// A plus between two parts, e.g. `x + 1`
+    Plus,
+
+    /// A minus between two parts, e.g. `x - 1`
+    Minus,
+}
+
+impl<R: DisplayRing> DisplayPart<R> {
+    /// Generate the [DisplayPart]s from a [Polynomial]
+    pub fn get_parts(poly: &Polynomial<R>, options: &DisplayOptions) -> Vec<DisplayPart<R>> {
+        let ring = poly.ring;
+
+        if poly.is_zero() {
+            return vec![DisplayPart {
+                negative: false,
+                coefficient: Some(ring.zero()),
+                parenthesized: false,
+                variable: None,
+            }];
+        }
+
+        let mut parts = Vec::new();
+        for (i, elem) in poly.elems.iter().enumerate().rev() {
+            if *elem == ring.zero() {
+                continue;
+            }
+
+            // A compound constant like `-1 + i` is displayed as it is
+            let constant = poly.elems.len() == 1 && ring.is_compound(elem);
+            let negative = !constant && ring.is_negative(elem, options);
+            let abs = if negative {
+                ring.neg(elem.clone())
+            } else {
+                elem.clone()
+            };
+
+            let coefficient = (abs != ring.one() || i == 0).then_some(abs);
+
+            let variable = match i {
+                0 => None,
+                1 => Some(None),
+                _ => Some(Some(i)),
+            };
+
+            let parenthesized = coefficient
+                .as_ref()
+                .is_some_and(|coeff| ring.is_compound(coeff) && (negative || variable.is_some()));
+
+            parts.push(DisplayPart {
+                negative,
+                coefficient,
+                parenthesized,
+                variable,
+            });
+        }
+
+        parts
+    }
+
+    /// The [Separator] in front of this part if it is the `i`-th part
+    pub fn separator(&self, i: usize) -> Option<Separator> {
+        match (i, self.negative) {
+            (0, false) => None,
+            (0, true) => Some(Separator::LeadingMinus),
+            (_, false) => Some(Separator::Plus),
+            (_, true) => Some(Separator::Minus),
+        }
+    }
+}
+
+impl<R: DisplayRing> Polynomial<R> {
+    /// Fold a displayable [Polynomial] into another type.
+    ///
+    /// This uses [DisplayPart]s for "rendering".
+    ///
+    /// - `options`: the [DisplayOptions] that decide which parts are negative
+    /// - `init`: the seed value of the folding
+    /// - `coeff`: if a coefficient can be displayed, do something to the accumulator, the flag
+    ///   tells if it must be put in parentheses
+    /// - `var`: if a variable can be displayed, do something to the accumulator
+    /// - `sep`: do something to the accumulator to add a [Separator] in front of a part
+    pub fn fold_display_parts<O, C, V, S>(
+        &self,
+        options: &DisplayOptions,
+        init: O,
+        coeff: C,
+        var: V,
+        sep: S,
+    ) -> O
+    where
+        C: Fn(&mut O, R::Element, bool),
+        V: Fn(&mut O, Option<usize>),
+        S: Fn(&mut O, Separator),
+    {
+        let parts = DisplayPart::get_parts(self, options);
+
+        parts
+            .into_iter()
+            .enumerate()
+            .fold(init, |mut acc, (i, part)| {
+                if let Some(s) = part.separator(i) {
+                    sep(&mut acc, s);
+                }
+
+                if let Some(c) = part.coefficient {
+                    coeff(&mut acc, c, part.parenthesized);
+                }
+
+                if let Some(exponent) = part.variable {
+                    var(&mut acc, exponent);
+                }
+
+                acc
+            })
+    }
+
+    /// Whether the polynomial needs parentheses as a factor of a product, i.e. it is a sum, a
+    /// compound constant like `1 + i` or negative
+    pub(crate) fn needs_parentheses(&self, options: &DisplayOptions) -> bool {
+        let parts = DisplayPart::get_parts(self, options);
+        parts.len() > 1
+            || parts.iter().any(|part| {
+                part.negative
+                    || part
+                        .coefficient
+                        .as_ref()
+                        .is_some_and(|coeff| self.ring.is_compound(coeff))
+            })
+    }
+
+    /// Map components of a displayable [Polynomial] to other types.
+    ///
+    /// This uses [DisplayPart]s for "rendering".
+    ///
+    /// - `options`: the [DisplayOptions] that decide which parts are negative
+    /// - `sep`: [Separator] in front of the components
+    /// - `coeff`: if a coefficient can be displayed, map it to something, the flag tells if it
+    ///   must be put in parentheses
+    /// - `var`: if a variable can be displayed, map it to something
+    pub fn map_display_parts<S, SF, C, CF, V, VF>(
+        &self,
+        options: &DisplayOptions,
+        sep: SF,
+        coeff: CF,
+        var: VF,
+    ) -> impl IntoIterator<Item = (Option<S>, Option<C>, Option<V>)>
+    where
+        SF: Fn(Separator) -> S,
+        CF: Fn(R::Element, bool) -> C,
+        VF: Fn(Option<usize>) -> V,
+    {
+        let parts = DisplayPart::get_parts(self, options);
+
+        parts.into_iter().enumerate().map(move |(i, part)| {
+            let s = part.separator(i).map(&sep);
+            let c = part.coefficient.map(|c| coeff(c, part.parenthesized));
+            let v = part.variable.map(&var);
+            (s, c, v)
+        })
+    }
+}
+
+/// Render a coefficient with [DisplayRing::render_elem], in parentheses if `parenthesized`
+pub(crate) fn render_coefficient<R>(
+    ring: R,
+    elem: &R::Element,
+    parenthesized: bool,
+    format: Format,
+    options: &DisplayOptions,
+) -> String
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    let rendered = ring.render_elem(elem, format, options);
+    if !parenthesized {
+        return rendered;
+    }
+
+    match format {
+        Format::Plain | Format::Unicode => format!("({rendered})"),
+        Format::Latex => format!(r"\left({rendered}\right)"),
+        Format::MathMl => format!("<mrow><mo>(</mo>{rendered}<mo>)</mo></mrow>"),
+    }
+}
+
+/// Options that control how polynomials are displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// The name of the variable, e.g. `x`, `t` or `α`
+    pub variable: String,
+
+    /// Which representatives are used for the coefficients
+    pub representation: Representation,
+
+    /// Put a `·` between coefficients and variables in the Unicode output, e.g. `3·x²`
+    pub multiplication_dot: bool,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            variable: String::from("x"),
+            representation: Representation::default(),
+            multiplication_dot: false,
+        }
+    }
+}
+
+/// The range of representatives that coefficients in `Z/nZ` are displayed with
+///
+/// This only changes the output, the arithmetic is the same in both cases.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Representation {
+    /// Representatives in `0..n`, e.g. `x^2 + 6x + 6` in `Z/7Z`
+    #[default]
+    Standard,
+
+    /// Representatives in `-(n-1)/2..=n/2`, e.g. `x^2 - x - 1` in `Z/7Z`
+    Balanced,
+}
+
+/// A [Polynomial] together with [DisplayOptions], see [Polynomial::display]
+pub struct PolynomialDisplay<'a, R: DisplayRing> {
+    poly: &'a Polynomial<R>,
+    options: &'a DisplayOptions,
+}
+
+impl<R: DisplayRing> Polynomial<R> {
+    /// Display the polynomial with custom [DisplayOptions]
+    pub fn display<'a>(&'a self, options: &'a DisplayOptions) -> PolynomialDisplay<'a, R> {
+        PolynomialDisplay {
+            poly: self,
+            options,
+        }
+    }
+}
+
+impl<R> fmt::Display for PolynomialDisplay<'_, R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    /// The alternate format `{:#}` uses [Polynomial::to_unicode]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.poly.to_unicode(self.options));
+        }
+
+        let ring = self.poly.ring;
+        let string = self.poly.fold_display_parts(
+            self.options,
+            String::new(),
+            |s, coeff, parenthesized| {
+                s.push_str(&render_coefficient(
+                    ring,
+                    &coeff,
+                    parenthesized,
+                    Format::Plain,
+                    self.options,
+                ));
+            },
+            |s, exp| {
+                s.push_str(&self.options.variable);
+                if let Some(exp) = exp {
+                    s.push('^');
+                    s.push_str(&exp.to_string());
+                }
+            },
+            |s, sep| {
+                s.push_str(match sep {
+                    Separator::LeadingMinus => "-",
+                    Separator::Plus => " + ",
+                    Separator::Minus => " - ",
+                });
+            },
+        );
+
+        write!(f, "{string}")
+    }
+}
+
+impl<R> fmt::Display for Polynomial<R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.display(&DisplayOptions::default()), f)
+    }
+}
+
+impl<R> fmt::Debug for Polynomial<R>
+where
+    R: Ring + fmt::Debug,
+    R::Element: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("ring", &self.ring)
+            .field("elems", &self.elems)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::IntegersModuloN;
+
+    #[test]
+    fn custom_variable() {
+        let poly = Polynomial::new(Integers, vec![1, 1, 3]);
+        assert_eq!(poly.to_string(), "3x^2 + x + 1");
+
+        let options = DisplayOptions {
+            variable: String::from("t"),
+            ..Default::default()
+        };
+        assert_eq!(poly.display(&options).to_string(), "3t^2 + t + 1");
+    }
+
+    #[test]
+    fn negative_coefficients() {
+        let poly = Polynomial::new(Integers, vec![-1, -3, 1]);
+        assert_eq!(poly.to_string(), "x^2 - 3x - 1");
+
+        let poly = Polynomial::new(Integers, vec![0, -1]);
+        assert_eq!(poly.to_string(), "-x");
+
+        let poly = Polynomial::new(Reals, vec![0.5, -1.5]);
+        assert_eq!(poly.to_string(), "-1.5x + 0.5");
+
+        let poly = Polynomial::new(IntegersModuloN::new(7), vec![6, 6, 1]);
+        assert_eq!(poly.to_string(), "x^2 + 6x + 6");
+    }
+
+    #[test]
+    fn balanced_representation() {
+        let options = DisplayOptions {
+            representation: Representation::Balanced,
+            ..Default::default()
+        };
+
+        let poly = Polynomial::new(IntegersModuloN::new(7), vec![6, 6, 1]);
+        assert_eq!(poly.display(&options).to_string(), "x^2 - x - 1");
+
+        let poly = Polynomial::new(IntegersModuloN::new(7), vec![3, 4]);
+        assert_eq!(poly.display(&options).to_string(), "-3x + 3");
+
+        let poly = Polynomial::new(IntegersModuloN::new(4), vec![3, 2]);
+        assert_eq!(poly.display(&options).to_string(), "2x - 1");
+    }
+
+    #[test]
+    fn nested_polynomials() {
+        use crate::polynomial::parse::ParsableRing;
+
+        let ring = PolynomialRing::new(Integers);
+        let poly = ring.parse_poly("(y + 1)x^2 + 2yx - y^2 + 3").unwrap();
+        assert_eq!(poly.to_string(), "(y + 1)x^2 + (2y)x - (y^2 - 3)");
+        assert_eq!(ring.parse_poly(&poly.to_string()).unwrap(), poly);
+
+        let poly = ring.parse_poly("-(y - 1)x + 2").unwrap();
+        assert_eq!(format!("{poly:#}"), "−(y − 1)x + 2");
+        assert_eq!(
+            poly.to_latex(&DisplayOptions::default()),
+            r"-\left(y - 1\right)x + 2"
+        );
+
+        let ring = PolynomialRing::with_variable(ring, "z");
+        assert_eq!(ring.unicode_name(), "ℤ[y][z]");
+        let poly = ring.parse_poly("zyx + z").unwrap();
+        assert_eq!(poly.to_string(), "((y)z)x + (z)");
+    }
+}
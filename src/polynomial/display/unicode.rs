@@ -0,0 +1,87 @@
+//! Pretty-printing polynomials with Unicode, e.g. `3x² − x + 1`
+
+use std::fmt;
+
+use super::{render_coefficient, DisplayOptions, DisplayRing, Format, Separator};
+use crate::polynomial::Polynomial;
+
+/// The superscript digits `⁰` to `⁹`
+pub(crate) const SUPERSCRIPT_DIGITS: [char; 10] =
+    ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl<R> Polynomial<R>
+where
+    R: DisplayRing,
+    R::Element: fmt::Display,
+{
+    /// Render the polynomial with superscript exponents and a proper minus sign
+    ///
+    /// This is also used for the alternate format `{:#}`.
+    pub fn to_unicode(&self, options: &DisplayOptions) -> String {
+        self.map_display_parts(
+            options,
+            |sep| match sep {
+                Separator::LeadingMinus => "−",
+                Separator::Plus => " + ",
+                Separator::Minus => " − ",
+            },
+            |coeff, parenthesized| {
+                render_coefficient(self.ring, &coeff, parenthesized, Format::Unicode, options)
+            },
+            |exp| match exp {
+                Some(exp) => format!("{}{}", options.variable, superscript(exp)),
+                None => options.variable.clone(),
+            },
+        )
+        .into_iter()
+        .map(|(sep, coeff, var)| {
+            let dot = options.multiplication_dot && coeff.is_some() && var.is_some();
+            format!(
+                "{}{}{}{}",
+                sep.unwrap_or_default(),
+                coeff.unwrap_or_default(),
+                if dot { "·" } else { "" },
+                var.unwrap_or_default()
+            )
+        })
+        .collect()
+    }
+}
+
+/// The polynomial ring over `ring` with Unicode symbols, e.g. `ℤ/5ℤ[x]`
+pub fn polynomial_ring<R: DisplayRing>(ring: &R, options: &DisplayOptions) -> String {
+    format!("{}[{}]", ring.unicode_name(), options.variable)
+}
+
+/// Write `n` with superscript digits, e.g. `¹²`
+pub fn superscript(n: usize) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| SUPERSCRIPT_DIGITS[c as usize - '0' as usize])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::{Integers, IntegersModuloN};
+
+    #[test]
+    fn unicode_polynomials() {
+        let poly = Polynomial::new(Integers, vec![1, -2, 0, 3]);
+        assert_eq!(format!("{poly:#}"), "3x³ − 2x + 1");
+
+        let poly = Polynomial::new(Integers, vec![-4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1]);
+        assert_eq!(format!("{poly:#}"), "−x¹² − 4");
+
+        let options = DisplayOptions {
+            multiplication_dot: true,
+            ..Default::default()
+        };
+        let poly = Polynomial::new(Integers, vec![5, 1, -3]);
+        assert_eq!(poly.to_unicode(&options), "−3·x² + x + 5");
+
+        let ring = IntegersModuloN::new(5);
+        assert_eq!(polynomial_ring(&ring, &options), "ℤ/5ℤ[x]");
+    }
+}
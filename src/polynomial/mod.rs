@@ -5,8 +5,9 @@ pub mod parse;
 
 use std::ops;
 
-use crate::ring::{Field, Ring};
-use parse::ParsableRing;
+use crate::ring::{Field, FiniteRing, Ring};
+use crate::Error;
+use parse::{ParsableRing, ParseError, ParseOptions};
 
 /// A polynomial over the ring `R`
 pub struct Polynomial<R: Ring> {
@@ -51,23 +52,124 @@ impl<R: Ring> Polynomial<R> {
         ret
     }
 
+    /// The monomial `elem * x^deg`
+    ///
+    /// Panics if the coefficients don't fit into memory, see [Self::try_single].
     pub fn single(ring: R, elem: R::Element, deg: usize) -> Self {
-        let mut elems = vec![ring.zero(); deg + 1];
-        elems[deg] = elem;
+        Self::try_single(ring, elem, deg).expect("the coefficients fit into memory")
+    }
 
-        let mut ret = Self::new(ring, elems);
-        ret.cut_trailing_zeros();
+    /// The monomial `elem * x^deg`, or [Error::Overflow] if the coefficients don't fit into memory
+    pub fn try_single(ring: R, elem: R::Element, deg: usize) -> crate::Result<Self> {
+        let elem = ring.id(elem);
+        if elem == ring.zero() {
+            return Ok(Self::zero(ring));
+        }
 
-        ret
+        let len = deg.checked_add(1).ok_or(Error::Overflow)?;
+        let mut elems = Vec::new();
+        elems.try_reserve_exact(len).map_err(|_| Error::Overflow)?;
+        elems.resize(deg, ring.zero());
+        elems.push(elem);
+
+        Ok(Self { ring, elems })
     }
 
-    pub fn parse(ring: R, input: &str) -> Option<Self>
+    pub fn parse(ring: R, input: &str) -> Result<Self, ParseError>
     where
         R: ParsableRing,
     {
         ring.parse_poly(input)
     }
 
+    pub fn parse_with(ring: R, input: &str, options: &ParseOptions) -> Result<Self, ParseError>
+    where
+        R: ParsableRing,
+    {
+        ring.parse_poly_with(input, options)
+    }
+
+    /// Compute `self^exp` using square-and-multiply
+    pub fn pow(self, mut exp: usize) -> Self {
+        let mut result = Polynomial::constant(self.ring, self.ring.one());
+        let mut base = self;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base.clone();
+            }
+
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+
+        result
+    }
+
+    /// `self + rhs`, or `None` if a coefficient overflows, see [Ring::checked_add]
+    pub(crate) fn checked_add(self, rhs: Polynomial<R>) -> Option<Polynomial<R>> {
+        let ring = self.ring;
+        let len = self.elems.len().max(rhs.elems.len());
+        let elems = (0..len)
+            .map(|i| ring.checked_add(self.coeff(i), rhs.coeff(i)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Polynomial::new(ring, elems))
+    }
+
+    /// `-self`, or `None` if a coefficient overflows, see [Ring::checked_neg]
+    pub(crate) fn checked_neg(self) -> Option<Polynomial<R>> {
+        let ring = self.ring;
+        let elems = self
+            .elems
+            .into_iter()
+            .map(|elem| ring.checked_neg(elem))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Polynomial::new(ring, elems))
+    }
+
+    /// `self * rhs`, or `None` if a coefficient overflows, see [Ring::checked_mul]
+    pub(crate) fn checked_mul(self, rhs: Polynomial<R>) -> Option<Polynomial<R>> {
+        if self.is_zero() || rhs.is_zero() {
+            return Some(Polynomial::zero(self.ring));
+        }
+
+        let ring = self.ring;
+        let mut out = Self::zeros(ring, self.elems.len() + rhs.elems.len() - 1);
+
+        for (i, a) in self.elems.iter().enumerate() {
+            for (j, b) in rhs.elems.iter().enumerate() {
+                let product = ring.checked_mul(a.clone(), b.clone())?;
+                out.elems[i + j] = ring.checked_add(out.elems[i + j].clone(), product)?;
+            }
+        }
+
+        out.cut_trailing_zeros();
+        Some(out)
+    }
+
+    /// `self^exp` like [Self::pow], or `None` if a coefficient overflows
+    pub(crate) fn checked_pow(self, mut exp: usize) -> Option<Polynomial<R>> {
+        let mut result = Polynomial::constant(self.ring, self.ring.one());
+        let mut base = self;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base.clone())?;
+            }
+
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone().checked_mul(base)?;
+            }
+        }
+
+        Some(result)
+    }
+
     pub fn add_elem(&mut self, elem: R::Element, deg: usize) {
         self.fill_with_zeros(deg + 1);
         self.add_elem_unsafe(elem, deg);
@@ -78,6 +180,18 @@ impl<R: Ring> Polynomial<R> {
         self.elems[deg] = self.ring.add(self.elems[deg].clone(), self.ring.id(elem));
     }
 
+    /// The ring of the coefficients
+    pub fn ring(&self) -> R {
+        self.ring
+    }
+
+    /// The value at `x` using Horner's method
+    pub fn eval(&self, x: R::Element) -> R::Element {
+        self.elems.iter().rev().fold(self.ring.zero(), |acc, elem| {
+            self.ring.add(self.ring.mul(acc, x.clone()), elem.clone())
+        })
+    }
+
     pub fn deg(&self) -> Option<usize> {
         (!self.elems.is_empty()).then(|| self.elems.len() - 1)
     }
@@ -86,6 +200,11 @@ impl<R: Ring> Polynomial<R> {
         self.elems.last().cloned().unwrap_or(self.ring.zero())
     }
 
+    /// The coefficient of `x^deg`
+    pub fn coeff(&self, deg: usize) -> R::Element {
+        self.elems.get(deg).cloned().unwrap_or(self.ring.zero())
+    }
+
     pub fn is_zero(&self) -> bool {
         self.elems.is_empty()
     }
@@ -96,6 +215,106 @@ impl<R: Ring> Polynomial<R> {
         }
     }
 
+    /// The product using Karatsuba's algorithm, which is faster than `*` for long factors
+    ///
+    /// This is used by the half-GCD, which is only quasi-linear with a sub-quadratic
+    /// multiplication. It sums the partial products in a different order than `*`, so the result
+    /// can differ over inexact rings like [crate::ring::Reals].
+    pub(crate) fn karatsuba_mul(self, rhs: Polynomial<R>) -> Polynomial<R> {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero(self.ring);
+        }
+
+        let ring = self.ring;
+        let len = self.elems.len() + rhs.elems.len() - 1;
+
+        let (longer, shorter) = if self.elems.len() >= rhs.elems.len() {
+            (self.elems, rhs.elems)
+        } else {
+            (rhs.elems, self.elems)
+        };
+
+        let elems = if shorter.len() < KARATSUBA_THRESHOLD {
+            schoolbook_mul(ring, &longer, &shorter)
+        } else {
+            // Multiply chunks of the longer factor that have the same length as the shorter one
+            let mut elems = vec![ring.zero(); len + shorter.len()];
+            for (i, chunk) in longer.chunks(shorter.len()).enumerate() {
+                let mut chunk = chunk.to_vec();
+                chunk.resize(shorter.len(), ring.zero());
+
+                let offset = i * shorter.len();
+                for (j, elem) in karatsuba_mul(ring, &chunk, &shorter)
+                    .into_iter()
+                    .enumerate()
+                {
+                    elems[offset + j] = ring.add(elems[offset + j].clone(), elem);
+                }
+            }
+            elems.truncate(len);
+            elems
+        };
+
+        let mut out = Self { ring, elems };
+        out.cut_trailing_zeros();
+        out
+    }
+
+    /// Division with remainder, see [Self::polynomial_division]
+    ///
+    /// This works over any ring if the leading coefficient of `rhs` is a unit (e.g. if `rhs` is
+    /// monic), otherwise it fails with [Error::NotInvertible]. It fails with [Error::Overflow]
+    /// if a coefficient doesn't fit, see [Ring::checked_mul].
+    pub fn try_polynomial_division(
+        self,
+        rhs: Polynomial<R>,
+    ) -> crate::Result<(Polynomial<R>, Polynomial<R>)> {
+        rhs.deg().ok_or(Error::DivisionByZero)?;
+        let lc_inverse = self.ring.try_inv(rhs.lc())?;
+        self.division_with_inverse(rhs, lc_inverse)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Division with remainder by a non-zero `rhs`, given the inverse of its leading coefficient
+    ///
+    /// This will be `None` iff a coefficient overflows.
+    fn division_with_inverse(
+        self,
+        rhs: Polynomial<R>,
+        lc_inverse: R::Element,
+    ) -> Option<(Polynomial<R>, Polynomial<R>)> {
+        let d = rhs.deg().expect("rhs is non-zero");
+        let ring = self.ring;
+
+        if self.elems.len() <= d {
+            return Some((Polynomial::zero(ring), self));
+        }
+
+        let mut q = Polynomial::zeros(ring, self.elems.len() - d);
+        let mut r = self;
+
+        for deg in (0..q.elems.len()).rev() {
+            let quotient = ring.checked_mul(r.elems[deg + d].clone(), lc_inverse.clone())?;
+
+            if quotient == ring.zero() {
+                continue;
+            }
+
+            for (i, elem) in rhs.elems[..d].iter().enumerate() {
+                let product = ring.checked_mul(quotient.clone(), elem.clone())?;
+                r.elems[deg + i] = ring.checked_sub(r.elems[deg + i].clone(), product)?;
+            }
+
+            q.elems[deg] = quotient;
+        }
+
+        r.elems.truncate(d);
+        q.cut_trailing_zeros();
+        r.cut_trailing_zeros();
+
+        Some((q, r))
+    }
+
     fn cut_trailing_zeros(&mut self) {
         for _ in 0..self.elems.len() {
             if let Some(elem) = self.elems.last() {
@@ -111,35 +330,89 @@ impl<R: Ring> Polynomial<R> {
     }
 }
 
+impl<R: FiniteRing> Polynomial<R> {
+    /// All roots by trying every element of the ring, so this is only feasible for small rings
+    ///
+    /// Every element is a root of the zero polynomial.
+    pub fn roots(&self) -> Vec<R::Element> {
+        self.ring
+            .elements()
+            .filter(|x| self.eval(x.clone()) == self.ring.zero())
+            .collect()
+    }
+}
+
 impl<F: Field> Polynomial<F> {
+    /// Division with remainder, `None` iff `rhs` is zero
     pub fn polynomial_division(self, rhs: Polynomial<F>) -> Option<(Polynomial<F>, Polynomial<F>)> {
-        if rhs.is_zero() {
-            return None;
-        }
+        let lc_inverse = self.ring.inv(rhs.lc())?;
+        let division = self.division_with_inverse(rhs, lc_inverse);
+        Some(division.expect("the coefficients of the quotient and remainder fit"))
+    }
 
+    /// Division with remainder that fails with [Error::DivisionByZero] iff `rhs` is zero and with
+    /// [Error::Overflow] if a coefficient doesn't fit
+    ///
+    /// Unlike [Self::try_polynomial_division], this only needs [Field::inv].
+    pub(crate) fn checked_polynomial_division(
+        self,
+        rhs: Polynomial<F>,
+    ) -> crate::Result<(Polynomial<F>, Polynomial<F>)> {
+        let lc_inverse = self.ring.inv(rhs.lc()).ok_or(Error::DivisionByZero)?;
+        self.division_with_inverse(rhs, lc_inverse)
+            .ok_or(Error::Overflow)
+    }
+
+    /// Divide by the leading coefficient so that it becomes one
+    ///
+    /// The zero polynomial stays zero.
+    pub fn monic(self) -> Polynomial<F> {
         let ring = self.ring;
-        let mut q = Polynomial::zeros(self.ring, self.elems.len());
-        let mut r = self;
-        let d = rhs.deg().expect("rhs is not zero");
+        let lc = self.lc();
+        if lc == ring.zero() {
+            return self;
+        }
 
-        while !r.is_zero() {
-            let r_deg = r.deg().expect("r is not zero");
+        let elems = self
+            .elems
+            .into_iter()
+            .map(|elem| ring.div(elem, lc.clone()).expect("lc is non-zero"))
+            .collect::<Vec<_>>();
+        Polynomial::new(ring, elems)
+    }
 
-            if r_deg < d {
-                break;
+    /// Compute `self^exp` modulo `modulus` using square-and-multiply
+    ///
+    /// This will be `None` iff `modulus` is zero.
+    pub fn pow_mod(self, exp: usize, modulus: Polynomial<F>) -> Option<Polynomial<F>> {
+        self.try_pow_mod(exp, modulus).ok()
+    }
+
+    /// Compute `self^exp` modulo `modulus`, see [Self::pow_mod]
+    pub fn try_pow_mod(
+        self,
+        mut exp: usize,
+        modulus: Polynomial<F>,
+    ) -> crate::Result<Polynomial<F>> {
+        let remainder = |poly: Polynomial<F>| {
+            poly.polynomial_division(modulus.clone())
+                .map(|(_, r)| r)
+                .ok_or(Error::DivisionByZero)
+        };
+
+        let mut base = remainder(self)?;
+        let mut result = remainder(Polynomial::constant(base.ring, base.ring.one()))?;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = remainder(result * base.clone())?;
             }
 
-            let deg = r_deg - d;
-            let quotient = ring.div(r.lc(), rhs.lc()).expect("rhs is not zero");
-            let t = Polynomial::single(ring, quotient, deg);
-            q += t.clone();
-            r -= t * rhs.clone();
+            base = remainder(base.clone() * base)?;
+            exp >>= 1;
         }
 
-        q.cut_trailing_zeros();
-        r.cut_trailing_zeros();
-
-        Some((q, r))
+        Ok(result)
     }
 }
 
@@ -228,6 +501,61 @@ impl<R: Ring> ops::Mul<Polynomial<R>> for Polynomial<R> {
     }
 }
 
+/// Minimal length of both factors from which on [Polynomial::karatsuba_mul] splits them
+const KARATSUBA_THRESHOLD: usize = 32;
+
+fn schoolbook_mul<R: Ring>(ring: R, a: &[R::Element], b: &[R::Element]) -> Vec<R::Element> {
+    let mut out = vec![ring.zero(); a.len() + b.len() - 1];
+
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = ring.add(out[i + j].clone(), ring.mul(x.clone(), y.clone()));
+        }
+    }
+
+    out
+}
+
+/// Karatsuba multiplication of two coefficient slices of equal length
+fn karatsuba_mul<R: Ring>(ring: R, a: &[R::Element], b: &[R::Element]) -> Vec<R::Element> {
+    let n = a.len();
+    if n < KARATSUBA_THRESHOLD {
+        return schoolbook_mul(ring, a, b);
+    }
+
+    let m = n / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let low = karatsuba_mul(ring, a0, b0);
+    let high = karatsuba_mul(ring, a1, b1);
+
+    let sum = |lo: &[R::Element], hi: &[R::Element]| -> Vec<R::Element> {
+        let mut out = hi.to_vec();
+        for (i, elem) in lo.iter().enumerate() {
+            out[i] = ring.add(out[i].clone(), elem.clone());
+        }
+        out
+    };
+    let mut mid = karatsuba_mul(ring, &sum(a0, a1), &sum(b0, b1));
+
+    for (i, elem) in low.iter().enumerate() {
+        mid[i] = ring.sub(mid[i].clone(), elem.clone());
+    }
+    for (i, elem) in high.iter().enumerate() {
+        mid[i] = ring.sub(mid[i].clone(), elem.clone());
+    }
+
+    let mut out = vec![ring.zero(); 2 * n - 1];
+    for (offset, part) in [(0, low), (m, mid), (2 * m, high)] {
+        for (i, elem) in part.into_iter().enumerate() {
+            out[offset + i] = ring.add(out[offset + i].clone(), elem);
+        }
+    }
+
+    out
+}
+
 impl<R: Ring> ops::MulAssign<Polynomial<R>> for Polynomial<R> {
     fn mul_assign(&mut self, rhs: Polynomial<R>) {
         let product = self.clone() * rhs;
@@ -269,4 +597,141 @@ mod tests {
         let product = Polynomial::single(Integers, 2, 2) * Polynomial::single(Integers, 3, 3);
         assert_eq!(product.deg(), Some(5));
     }
+
+    #[test]
+    fn division_over_rings() {
+        use crate::ring::{IntegersModuloN, PolynomialRing, Ring};
+
+        let ring = IntegersModuloN::new(12);
+        assert!(ring.is_unit(&5));
+        assert_eq!(ring.try_inv(7), Ok(7));
+        assert_eq!(ring.try_inv(8), Err(Error::NotInvertible));
+        assert_eq!(ring.try_inv(0), Err(Error::DivisionByZero));
+        assert!(!Integers.is_unit(&2));
+        assert!(PolynomialRing::new(ring).is_unit(&Polynomial::constant(ring, 11)));
+
+        let lhs = Polynomial::new(ring, vec![7, 5, 0, 1]);
+        let (q, r) = lhs
+            .clone()
+            .try_polynomial_division(Polynomial::new(ring, vec![1, 5]))
+            .unwrap();
+        assert_eq!(q * Polynomial::new(ring, vec![1, 5]) + r.clone(), lhs);
+        assert_eq!(r.deg(), Some(0));
+
+        assert_eq!(
+            lhs.try_polynomial_division(Polynomial::new(ring, vec![5, 2])),
+            Err(Error::NotInvertible)
+        );
+    }
+
+    #[test]
+    fn division_over_custom_field() {
+        use crate::euclid::extended_euclidean;
+        use crate::ring::{Field, PolynomialRing, Ring};
+
+        /// `Z/5Z` that only implements the required methods
+        #[derive(Debug, Clone, Copy)]
+        struct Gf5;
+
+        impl Ring for Gf5 {
+            type Element = u8;
+
+            fn zero(&self) -> u8 {
+                0
+            }
+
+            fn one(&self) -> u8 {
+                1
+            }
+
+            fn add(&self, lhs: u8, rhs: u8) -> u8 {
+                (lhs + rhs) % 5
+            }
+
+            fn neg(&self, elem: u8) -> u8 {
+                (5 - elem) % 5
+            }
+
+            fn mul(&self, lhs: u8, rhs: u8) -> u8 {
+                (lhs * rhs) % 5
+            }
+        }
+
+        impl Field for Gf5 {
+            fn inv(&self, elem: u8) -> Option<u8> {
+                (1..5).find(|&x| self.mul(elem, x) == 1)
+            }
+        }
+
+        let lhs = Polynomial::new(Gf5, vec![3, 0, 1]);
+        let rhs = Polynomial::new(Gf5, vec![1, 2]);
+        let (q, r) = lhs.clone().polynomial_division(rhs.clone()).unwrap();
+        assert_eq!(q * rhs.clone() + r, lhs);
+
+        let (gcd, _, _) = extended_euclidean(PolynomialRing::new(Gf5), lhs, rhs).unwrap();
+        assert_eq!(gcd, Polynomial::constant(Gf5, 1));
+    }
+
+    #[test]
+    fn roots_by_enumeration() {
+        use crate::ring::{IntegersModuloN, MontgomeryModuloP};
+
+        let ring = IntegersModuloN::new(8);
+        assert_eq!((ring.characteristic(), ring.order()), (8, 8));
+        assert_eq!(
+            ring.elements().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5, 6, 7]
+        );
+
+        let poly = Polynomial::new(ring, vec![-1, 0, 1]);
+        assert_eq!(poly.eval(3), 0);
+        assert_eq!(poly.roots(), [1, 3, 5, 7]);
+
+        let field = MontgomeryModuloP::new(13).unwrap();
+        let poly = Polynomial::parse(field, "x^2 + 1").unwrap();
+        let roots: Vec<isize> = poly.roots().into_iter().map(|x| field.to_int(x)).collect();
+        assert_eq!(roots, [5, 8]);
+    }
+
+    #[test]
+    fn errors() {
+        use crate::ring::IntegersModuloP;
+
+        assert_eq!(
+            Polynomial::try_single(Integers, 1, usize::MAX),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Polynomial::try_single(Integers, 0, usize::MAX),
+            Ok(Polynomial::zero(Integers))
+        );
+
+        let field = IntegersModuloP::try_new(5).unwrap();
+        let x = Polynomial::single(field, 1, 1);
+        assert_eq!(
+            x.clone().try_polynomial_division(Polynomial::zero(field)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            x.try_pow_mod(3, Polynomial::zero(field)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            IntegersModuloP::try_new(12).err(),
+            Some(Error::NotPrime(12))
+        );
+        assert_eq!(
+            crate::euclid::try_extended_euclidean(Integers, 0, 0),
+            Err(Error::AllZero)
+        );
+    }
+
+    #[test]
+    fn karatsuba_agrees_with_schoolbook() {
+        let a: Vec<isize> = (0..100).map(|i| (i * 37) % 23 - 11).collect();
+        let b: Vec<isize> = (0..70).map(|i| (i * 13) % 17 - 8).collect();
+
+        let (a, b) = (Polynomial::new(Integers, a), Polynomial::new(Integers, b));
+        assert_eq!(a.clone().karatsuba_mul(b.clone()), a * b);
+    }
 }
@@ -0,0 +1,152 @@
+//! Splitting the input into tokens
+
+use std::ops::Range;
+
+use super::{ParseError, ParseErrorReason};
+use crate::polynomial::display::unicode::SUPERSCRIPT_DIGITS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Number,
+    Variable,
+    /// Another name that stands for a ring element, see [super::ParsableRing::symbols]
+    Symbol,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Caret,
+    /// Superscript digits like `²`, an exponent without a caret
+    Superscript,
+    LeftParen,
+    RightParen,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Token {
+    pub kind: TokenKind,
+
+    /// The byte range in the input
+    pub span: Range<usize>,
+}
+
+/// Split `input` into [Token]s, skipping whitespace
+///
+/// If names overlap, the longest one is used. The `variable` wins over `symbols` of the same
+/// length.
+pub(super) fn tokenize(
+    input: &str,
+    variable: &str,
+    symbols: &[String],
+) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    let names = std::iter::once((variable, TokenKind::Variable))
+        .chain(
+            symbols
+                .iter()
+                .map(|symbol| (symbol.as_str(), TokenKind::Symbol)),
+        )
+        .filter(|(name, _)| !name.is_empty());
+
+    while let Some((start, c)) = chars.next() {
+        let name = names
+            .clone()
+            .filter(|(name, _)| input[start..].starts_with(name))
+            .reduce(|longest, name| {
+                if name.0.len() > longest.0.len() {
+                    name
+                } else {
+                    longest
+                }
+            });
+
+        if let Some((name, kind)) = name {
+            let end = start + name.len();
+            while chars.next_if(|(i, _)| *i < end).is_some() {}
+
+            tokens.push(Token {
+                kind,
+                span: start..end,
+            });
+            continue;
+        }
+
+        let kind = match c {
+            c if c.is_whitespace() => continue,
+            '0'..='9' | '.' => {
+                let end = number_end(input, start);
+                while chars.next_if(|(i, _)| *i < end).is_some() {}
+                TokenKind::Number
+            }
+            c if SUPERSCRIPT_DIGITS.contains(&c) => {
+                while chars
+                    .next_if(|(_, c)| SUPERSCRIPT_DIGITS.contains(c))
+                    .is_some()
+                {}
+                TokenKind::Superscript
+            }
+            '+' => TokenKind::Plus,
+            '-' | '−' => TokenKind::Minus,
+            '*' | '·' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '^' => TokenKind::Caret,
+            '(' => TokenKind::LeftParen,
+            ')' => TokenKind::RightParen,
+            _ => {
+                let span = start..start + c.len_utf8();
+                return Err(ParseError::new(
+                    input,
+                    span,
+                    ParseErrorReason::UnexpectedCharacter,
+                ));
+            }
+        };
+
+        let end = chars.peek().map_or(input.len(), |(i, _)| *i);
+        tokens.push(Token {
+            kind,
+            span: start..end,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// The end of the number that starts at `start`, including an exponent like `e-3`
+///
+/// An `e` is only part of the number if digits follow, so `2e` is still `2` times `e`.
+fn number_end(input: &str, start: usize) -> usize {
+    let bytes = input.as_bytes();
+    let digits_end = |mut i: usize, dot: bool| {
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || (dot && bytes[i] == b'.')) {
+            i += 1;
+        }
+        i
+    };
+
+    let end = digits_end(start, true);
+    if !matches!(bytes.get(end), Some(b'e' | b'E')) {
+        return end;
+    }
+
+    let mut exponent = end + 1;
+    if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+        exponent += 1;
+    }
+
+    if bytes.get(exponent).is_some_and(u8::is_ascii_digit) {
+        digits_end(exponent, false)
+    } else {
+        end
+    }
+}
+
+/// The value of a [TokenKind::Superscript] token, e.g. `12` for `¹²`
+pub(super) fn superscript_value(text: &str) -> Option<usize> {
+    text.chars().try_fold(0_usize, |acc, c| {
+        let digit = SUPERSCRIPT_DIGITS.iter().position(|&d| d == c)?;
+        acc.checked_mul(10)?.checked_add(digit)
+    })
+}
@@ -0,0 +1,480 @@
+//! Parsing of polynomials
+
+mod lexer;
+mod parser;
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::ring::{
+    inverse_modulo, Field, GaloisField, GaussianInteger, GaussianIntegers, Integers,
+    IntegersModuloAny, MontgomeryModuloP, PolynomialRing, QuadraticField, QuadraticNumber,
+    Rational, Rationals, Reals, Ring,
+};
+
+use super::Polynomial;
+use parser::Parser;
+
+/// A ring where polynomials can be parsed
+pub trait ParsableRing: Ring {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element>;
+
+    /// The value of a fraction like `1/3`, `None` if it is not an element of the ring
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        let _ = (numerator, denominator);
+        None
+    }
+
+    /// Names besides the variable that stand for ring elements, see [Self::parse_symbol]
+    fn symbols(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// The element that a name from [Self::symbols] stands for
+    fn parse_symbol(&self, symbol: &str) -> Option<Self::Element> {
+        let _ = symbol;
+        None
+    }
+
+    /// The total degree of `elem` in the variables of nested polynomial rings
+    ///
+    /// It is bounded by [MAX_DEGREE] like the degree in the variable `x`, so that powers like
+    /// `y^100000000` are rejected before they are expanded. This is zero if the ring has no
+    /// variables.
+    fn nested_degree(&self, elem: &Self::Element) -> usize {
+        let _ = elem;
+        0
+    }
+
+    /// Parse a polynomial expression in the variable `x`
+    ///
+    /// Besides sums of terms like `3x^2`, this supports subtraction, negation, products (with
+    /// `*`, `·` or by juxtaposition), parentheses and integer powers of sub-expressions.
+    /// Coefficients can be fractions like `1/3` or use scientific notation like `2.5e3`.
+    fn parse_poly(&self, input: &str) -> Result<Polynomial<Self>, ParseError> {
+        self.parse_poly_with(input, &ParseOptions::default())
+    }
+
+    /// Parse a polynomial expression like [Self::parse_poly] but with custom [ParseOptions]
+    fn parse_poly_with(
+        &self,
+        input: &str,
+        options: &ParseOptions,
+    ) -> Result<Polynomial<Self>, ParseError> {
+        let tokens = lexer::tokenize(input, &options.variable, &self.symbols())?;
+        Parser::new(*self, input, tokens).parse()
+    }
+}
+
+/// Options that control how polynomials are parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The name of the variable, e.g. `x`, `t` or `α`
+    ///
+    /// It must neither be empty nor start with a digit or an operator.
+    pub variable: String,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            variable: String::from("x"),
+        }
+    }
+}
+
+/// An error that occurred while parsing a polynomial
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The byte range of the offending token in the input
+    pub span: Range<usize>,
+
+    /// The offending token (empty at the end of the input)
+    pub token: String,
+
+    pub reason: ParseErrorReason,
+}
+
+/// The reason why parsing failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorReason {
+    /// The coefficient is not a number
+    BadCoefficient,
+
+    /// The exponent is not a non-negative integer
+    BadExponent,
+
+    /// The character is not allowed at this position
+    UnexpectedCharacter,
+
+    /// The input ended while a term was expected
+    UnexpectedEnd,
+
+    /// The opening parenthesis is never closed
+    UnclosedParenthesis,
+
+    /// The coefficient is a number, but not an element of the ring
+    CoefficientNotInRing,
+
+    /// The power would have a degree larger than [MAX_DEGREE]
+    DegreeTooLarge,
+
+    /// A coefficient of the expression doesn't fit into the elements of the ring
+    Overflow,
+}
+
+/// The largest degree of a power like `(x + 1)^n` that is expanded while parsing
+///
+/// Over nested polynomial rings, this bounds the total degree in all variables.
+pub const MAX_DEGREE: usize = 1 << 16;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason {
+            ParseErrorReason::BadCoefficient => write!(f, "bad coefficient `{}`", self.token),
+            ParseErrorReason::BadExponent => write!(f, "bad exponent `{}`", self.token),
+            ParseErrorReason::UnexpectedCharacter => {
+                write!(f, "unexpected character `{}`", self.token)
+            }
+            ParseErrorReason::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParseErrorReason::UnclosedParenthesis => write!(f, "unclosed parenthesis"),
+            ParseErrorReason::CoefficientNotInRing => {
+                write!(f, "coefficient `{}` is not in the ring", self.token)
+            }
+            ParseErrorReason::DegreeTooLarge => write!(
+                f,
+                "the degree of `{}` is larger than {MAX_DEGREE}",
+                self.token
+            ),
+            ParseErrorReason::Overflow => {
+                write!(f, "the coefficients of `{}` overflow", self.token)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    fn new(input: &str, span: Range<usize>, reason: ParseErrorReason) -> Self {
+        Self {
+            token: input[span.clone()].to_string(),
+            span,
+            reason,
+        }
+    }
+}
+
+/// A coefficient that can be read as some number is valid, but just not in the ring
+fn coefficient_error_reason(coeff: &str) -> ParseErrorReason {
+    if coeff.parse::<f64>().is_ok() {
+        ParseErrorReason::CoefficientNotInRing
+    } else {
+        ParseErrorReason::BadCoefficient
+    }
+}
+
+impl ParsableRing for Reals {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse().ok()
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        self.div(numerator, denominator)
+    }
+}
+
+impl ParsableRing for Integers {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse().ok()
+    }
+}
+
+/// Coefficients are decimal fractions, e.g. `1/3x - 0.25`
+impl ParsableRing for Rationals {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        Rational::parse(input)
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        numerator.checked_mul(denominator.inv()?)
+    }
+}
+
+/// The imaginary unit is the symbol `i`, e.g. `(3 + 4i)x`
+impl ParsableRing for GaussianIntegers {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse::<isize>().ok().map(GaussianInteger::from)
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        vec![String::from("i")]
+    }
+
+    fn parse_symbol(&self, symbol: &str) -> Option<Self::Element> {
+        (symbol == "i").then(GaussianInteger::i)
+    }
+}
+
+/// Coefficients are decimal fractions and `√d` can also be written as `sqrt(d)`, e.g.
+/// `(1/2 + 3√2)x`
+impl ParsableRing for QuadraticField {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        Rational::parse(input).map(QuadraticNumber::from)
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        self.div(numerator, denominator)
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        vec![format!("√{}", self.d()), format!("sqrt({})", self.d())]
+    }
+
+    fn parse_symbol(&self, symbol: &str) -> Option<Self::Element> {
+        self.symbols()
+            .iter()
+            .any(|s| s == symbol)
+            .then(|| self.sqrt_d())
+    }
+}
+
+/// The generator is the symbol `α`, e.g. `(α + 1)x^2 + α^7`
+impl ParsableRing for GaloisField {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse().ok().map(|n| self.from_int(n))
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        self.div(numerator, denominator)
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        vec![String::from("α")]
+    }
+
+    fn parse_symbol(&self, symbol: &str) -> Option<Self::Element> {
+        (symbol == "α").then(|| self.generator())
+    }
+}
+
+impl<T: IntegersModuloAny> ParsableRing for T {
+    /// Numbers up to `i128::MAX` are reduced, so every residue of a large modulus can be parsed
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse().ok().map(|e| self.reduce(e))
+    }
+
+    /// A fraction is an element if the denominator is a unit, even if `n` is not prime
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        inverse_modulo(denominator, self.n()).map(|inverse| self.mul(numerator, inverse))
+    }
+}
+
+impl ParsableRing for MontgomeryModuloP {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        input.parse().ok().map(|e| self.from_int(e))
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        self.div(numerator, denominator)
+    }
+}
+
+/// Coefficients can use the variable of the ring, e.g. `(y + 1)x^2 + 2yx`
+impl<R: ParsableRing> ParsableRing for PolynomialRing<R> {
+    fn parse_elem(&self, input: &str) -> Option<Self::Element> {
+        let ring = self.base_ring();
+        ring.parse_elem(input)
+            .map(|elem| Polynomial::constant(ring, elem))
+    }
+
+    fn parse_fraction(
+        &self,
+        numerator: Self::Element,
+        denominator: Self::Element,
+    ) -> Option<Self::Element> {
+        let ring = self.base_ring();
+        ring.parse_fraction(numerator.coeff(0), denominator.coeff(0))
+            .map(|elem| Polynomial::constant(ring, elem))
+    }
+
+    fn nested_degree(&self, elem: &Self::Element) -> usize {
+        let ring = self.base_ring();
+        (0..=elem.deg().unwrap_or(0))
+            .map(|deg| deg + ring.nested_degree(&elem.coeff(deg)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn symbols(&self) -> Vec<String> {
+        let mut symbols = self.base_ring().symbols();
+        symbols.push(self.variable().to_string());
+        symbols
+    }
+
+    fn parse_symbol(&self, symbol: &str) -> Option<Self::Element> {
+        let ring = self.base_ring();
+
+        if symbol == self.variable() {
+            return Some(Polynomial::single(ring, ring.one(), 1));
+        }
+
+        ring.parse_symbol(symbol)
+            .map(|elem| Polynomial::constant(ring, elem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::IntegersModuloN;
+
+    #[test]
+    fn expressions() {
+        let parse = |input| Integers.parse_poly(input).unwrap();
+        let poly = |elems: &[isize]| Polynomial::new(Integers, elems);
+
+        assert_eq!(parse("3x^2 + x + 7"), poly(&[7, 1, 3]));
+        assert_eq!(parse("x^2 - 1"), poly(&[-1, 0, 1]));
+        assert_eq!(parse("-3x"), poly(&[0, -3]));
+        assert_eq!(parse("-x^2"), poly(&[0, 0, -1]));
+        assert_eq!(parse("(x+1)(x-1)"), poly(&[-1, 0, 1]));
+        assert_eq!(parse("2*x"), poly(&[0, 2]));
+        assert_eq!(parse("(x+1)^3 - -x"), poly(&[1, 4, 3, 1]));
+        assert_eq!(parse("2(x + 1)x"), poly(&[0, 2, 2]));
+        assert_eq!(parse("3x² + x¹⁰"), poly(&[0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(parse("(x + 1)²"), poly(&[1, 2, 1]));
+        assert_eq!(parse("2·x − 1"), poly(&[-1, 2]));
+        assert_eq!(parse("−x³ + 2x"), poly(&[0, 2, 0, -1]));
+    }
+
+    #[test]
+    fn coefficients() {
+        let parse = |input| Reals.parse_poly(input).unwrap();
+        let poly = |elems: &[f64]| Polynomial::new(Reals, elems);
+
+        assert_eq!(parse("1e-3x"), poly(&[0.0, 0.001]));
+        assert_eq!(parse("2.5e3x^2 + 1E2"), poly(&[100.0, 0.0, 2500.0]));
+        assert_eq!(parse("1/4x − 1/2"), poly(&[-0.5, 0.25]));
+
+        let modulo = IntegersModuloN::new(8);
+        let poly = modulo.parse_poly("3/5x").unwrap();
+        assert_eq!(poly, Polynomial::new(modulo, vec![0, 7]));
+
+        let err = modulo.parse_poly("x + 1/2").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (4..7, ParseErrorReason::CoefficientNotInRing)
+        );
+
+        let err = Integers.parse_poly("1e3").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (0..3, ParseErrorReason::CoefficientNotInRing)
+        );
+    }
+
+    #[test]
+    fn custom_variable() {
+        let options = ParseOptions {
+            variable: String::from("α"),
+        };
+
+        let poly = Integers
+            .parse_poly_with("3α^2 - (α + 1)", &options)
+            .unwrap();
+        assert_eq!(poly, Polynomial::new(Integers, vec![-1, -1, 3]));
+
+        let err = Integers.parse_poly_with("3x", &options).unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (1..2, ParseErrorReason::UnexpectedCharacter)
+        );
+    }
+
+    #[test]
+    fn error_positions() {
+        let err = Integers.parse_poly("3x^2 + 1.5x").unwrap_err();
+        assert_eq!(err.span, 7..10);
+        assert_eq!(err.token, "1.5");
+        assert_eq!(err.reason, ParseErrorReason::CoefficientNotInRing);
+
+        let err = Reals.parse_poly("x^ a + 2").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (3..4, ParseErrorReason::UnexpectedCharacter)
+        );
+
+        let err = Reals.parse_poly("x^-2").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (2..3, ParseErrorReason::BadExponent)
+        );
+
+        let err = Reals.parse_poly("x + ").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (4..4, ParseErrorReason::UnexpectedEnd)
+        );
+
+        let err = Reals.parse_poly("2 (x + 1").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (2..3, ParseErrorReason::UnclosedParenthesis)
+        );
+
+        let err = Reals.parse_poly("x)").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (1..2, ParseErrorReason::UnexpectedCharacter)
+        );
+
+        let err = Reals.parse_poly("1 + x^10000000000").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (4..17, ParseErrorReason::DegreeTooLarge)
+        );
+
+        let err = Integers.parse_poly("x - (2x)^64").unwrap_err();
+        assert_eq!((err.span, err.reason), (4..11, ParseErrorReason::Overflow));
+        assert!(Integers.parse_poly("-9223372036854775807 - 2").is_err());
+        assert!(Integers.parse_poly("(2x)^62").is_ok());
+
+        let nested = PolynomialRing::new(Integers);
+        let err = nested.parse_poly("(2y)^64").unwrap_err();
+        assert_eq!((err.span, err.reason), (0..7, ParseErrorReason::Overflow));
+        let err = nested.parse_poly("y^100000000x").unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (0..11, ParseErrorReason::DegreeTooLarge)
+        );
+    }
+}
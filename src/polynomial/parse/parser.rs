@@ -0,0 +1,238 @@
+//! Precedence climbing parser that evaluates expressions into polynomials
+//!
+//! ```text
+//! expr   := term (("+" | "-") term)*
+//! term   := unary ("*"? unary)*
+//! unary  := "-" unary | power
+//! power  := atom ("^" NUMBER | SUPERSCRIPT)?
+//! atom   := NUMBER ("/" NUMBER)? | VARIABLE | SYMBOL | "(" expr ")"
+//! ```
+//!
+//! The `*` can be omitted if the right factor starts with a variable, a symbol or a parenthesis.
+//! Exponents can be written with superscript digits, e.g. `x²`. A fraction of two numbers like
+//! `1/3` is a single coefficient, so `1/3x` is a third of `x`.
+
+use std::ops::Range;
+
+use super::lexer::{superscript_value, Token, TokenKind};
+use super::{coefficient_error_reason, ParsableRing, ParseError, ParseErrorReason, MAX_DEGREE};
+use crate::polynomial::Polynomial;
+use crate::ring::PolynomialRing;
+
+pub(super) struct Parser<'a, R: ParsableRing> {
+    ring: R,
+    input: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl<'a, R: ParsableRing> Parser<'a, R> {
+    pub fn new(ring: R, input: &'a str, tokens: Vec<Token>) -> Self {
+        Self {
+            ring,
+            input,
+            tokens,
+            pos: 0,
+        }
+    }
+
+    /// Parse the whole input
+    pub fn parse(mut self) -> Result<Polynomial<R>, ParseError> {
+        let poly = self.expr()?;
+
+        if let Some(token) = self.peek() {
+            return Err(self.error(token.span.clone(), ParseErrorReason::UnexpectedCharacter));
+        }
+
+        Ok(poly)
+    }
+
+    fn expr(&mut self) -> Result<Polynomial<R>, ParseError> {
+        let start = self.pos;
+        let mut poly = self.term()?;
+
+        loop {
+            let sum = match self.peek_kind() {
+                Some(TokenKind::Plus) => {
+                    self.pos += 1;
+                    poly.checked_add(self.term()?)
+                }
+                Some(TokenKind::Minus) => {
+                    self.pos += 1;
+                    let rhs = self.term()?.checked_neg();
+                    rhs.and_then(|rhs| poly.checked_add(rhs))
+                }
+                _ => return Ok(poly),
+            };
+
+            poly = self.checked(sum, start)?;
+        }
+    }
+
+    fn term(&mut self) -> Result<Polynomial<R>, ParseError> {
+        let start = self.pos;
+        let mut poly = self.unary()?;
+
+        loop {
+            let product = match self.peek_kind() {
+                Some(TokenKind::Star) => {
+                    self.pos += 1;
+                    poly.checked_mul(self.unary()?)
+                }
+                Some(TokenKind::Variable | TokenKind::Symbol | TokenKind::LeftParen) => {
+                    poly.checked_mul(self.unary()?)
+                }
+                _ => return Ok(poly),
+            };
+
+            poly = self.checked(product, start)?;
+        }
+    }
+
+    fn unary(&mut self) -> Result<Polynomial<R>, ParseError> {
+        if self.peek_kind() == Some(TokenKind::Minus) {
+            let start = self.pos;
+            self.pos += 1;
+            let neg = self.unary()?.checked_neg();
+            return self.checked(neg, start);
+        }
+
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Polynomial<R>, ParseError> {
+        let start = self.pos;
+        let base = self.atom()?;
+
+        let token = match self.peek_kind() {
+            Some(TokenKind::Caret) => {
+                self.pos += 1;
+                self.next()?
+            }
+            Some(TokenKind::Superscript) => self.next()?,
+            _ => return Ok(base),
+        };
+
+        let text = &self.input[token.span.clone()];
+        let exponent = match token.kind {
+            TokenKind::Number => text.parse().ok(),
+            TokenKind::Superscript => superscript_value(text),
+            _ => None,
+        };
+
+        let exp = exponent.ok_or_else(|| self.error(token.span, ParseErrorReason::BadExponent))?;
+
+        // Check the degree before expanding, e.g. `x^10000000000` wouldn't fit into memory. The
+        // total degree also covers the variables of nested polynomial rings.
+        let deg = PolynomialRing::new(self.ring)
+            .nested_degree(&base)
+            .checked_mul(exp);
+        if deg.is_none_or(|deg| deg > MAX_DEGREE) {
+            return Err(self.error(self.span_since(start), ParseErrorReason::DegreeTooLarge));
+        }
+
+        let power = base.checked_pow(exp);
+        self.checked(power, start)
+    }
+
+    fn atom(&mut self) -> Result<Polynomial<R>, ParseError> {
+        let token = self.next()?;
+
+        match token.kind {
+            TokenKind::Number => {
+                let numerator = self.number(&token)?;
+
+                if self.peek_kind() != Some(TokenKind::Slash) {
+                    return Ok(Polynomial::constant(self.ring, numerator));
+                }
+                self.pos += 1;
+
+                let denominator_token = self.next()?;
+                if denominator_token.kind != TokenKind::Number {
+                    return Err(
+                        self.error(denominator_token.span, ParseErrorReason::BadCoefficient)
+                    );
+                }
+                let denominator = self.number(&denominator_token)?;
+
+                let span = token.span.start..denominator_token.span.end;
+                let elem = self
+                    .ring
+                    .parse_fraction(numerator, denominator)
+                    .ok_or_else(|| self.error(span, ParseErrorReason::CoefficientNotInRing))?;
+
+                Ok(Polynomial::constant(self.ring, elem))
+            }
+            TokenKind::Variable => Ok(Polynomial::single(self.ring, self.ring.one(), 1)),
+            TokenKind::Symbol => {
+                let elem = self
+                    .ring
+                    .parse_symbol(&self.input[token.span.clone()])
+                    .ok_or_else(|| {
+                        self.error(token.span.clone(), ParseErrorReason::UnexpectedCharacter)
+                    })?;
+
+                Ok(Polynomial::constant(self.ring, elem))
+            }
+            TokenKind::LeftParen => {
+                let poly = self.expr()?;
+
+                if self.peek_kind() != Some(TokenKind::RightParen) {
+                    return Err(self.error(token.span, ParseErrorReason::UnclosedParenthesis));
+                }
+                self.pos += 1;
+
+                Ok(poly)
+            }
+            _ => Err(self.error(token.span, ParseErrorReason::UnexpectedCharacter)),
+        }
+    }
+
+    /// The ring element of a [TokenKind::Number] token
+    fn number(&self, token: &Token) -> Result<R::Element, ParseError> {
+        let number = &self.input[token.span.clone()];
+
+        self.ring
+            .parse_elem(number)
+            .ok_or_else(|| self.error(token.span.clone(), coefficient_error_reason(number)))
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_kind(&self) -> Option<TokenKind> {
+        self.peek().map(|token| token.kind)
+    }
+
+    /// Consume the next token that must exist
+    fn next(&mut self) -> Result<Token, ParseError> {
+        let token = self.peek().cloned().ok_or_else(|| {
+            let end = self.input.len();
+            self.error(end..end, ParseErrorReason::UnexpectedEnd)
+        })?;
+
+        self.pos += 1;
+        Ok(token)
+    }
+
+    /// The span from the token at `start` to the last consumed token
+    fn span_since(&self, start: usize) -> Range<usize> {
+        self.tokens[start].span.start..self.tokens[self.pos - 1].span.end
+    }
+
+    /// The result of a checked operation on the tokens since `start`, see [Ring::checked_add]
+    ///
+    /// [Ring::checked_add]: crate::ring::Ring::checked_add
+    fn checked(
+        &self,
+        poly: Option<Polynomial<R>>,
+        start: usize,
+    ) -> Result<Polynomial<R>, ParseError> {
+        poly.ok_or_else(|| self.error(self.span_since(start), ParseErrorReason::Overflow))
+    }
+
+    fn error(&self, span: Range<usize>, reason: ParseErrorReason) -> ParseError {
+        ParseError::new(self.input, span, reason)
+    }
+}
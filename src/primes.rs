@@ -0,0 +1,192 @@
+//! Primality testing and factorization of machine integers
+
+/// Bases for which Miller–Rabin is deterministic for all 64-bit integers
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Whether `n` is prime, using a deterministic Miller–Rabin test
+pub fn is_prime(n: usize) -> bool {
+    let n = n as u64;
+    if n < 2 {
+        return false;
+    }
+
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = d * 2^s with odd d
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    WITNESSES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        (1..s).any(|_| {
+            x = mul_mod(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+/// The smallest prime larger than `n`, `None` if it doesn't fit into a `usize`
+pub fn next_prime(n: usize) -> Option<usize> {
+    let mut candidate = n.checked_add(1)?;
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(1)?;
+    }
+
+    Some(candidate)
+}
+
+/// The prime factorization of `n` as pairs `(p, e)` of primes and exponents in ascending order
+///
+/// Small factors are found by trial division and large ones with Pollard's rho algorithm. The
+/// factorization of 1 is empty and 0 has none, so this will be `None` iff `n` is 0.
+pub fn factorize(n: usize) -> Option<Vec<(usize, u32)>> {
+    if n == 0 {
+        return None;
+    }
+
+    let mut primes = Vec::new();
+    let mut rest = n;
+    for p in 2..100 {
+        while rest.is_multiple_of(p) {
+            primes.push(p);
+            rest /= p;
+        }
+    }
+    split(rest, &mut primes);
+    primes.sort_unstable();
+
+    let mut factors: Vec<(usize, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+
+    Some(factors)
+}
+
+/// The smallest primitive root modulo `p`, i.e. a generator of the multiplicative group of `Z/pZ`
+///
+/// This will be `None` iff `p` is not prime.
+pub fn primitive_root(p: usize) -> Option<usize> {
+    if !is_prime(p) {
+        return None;
+    }
+
+    // `g` generates the group of order `p - 1` iff no `g^((p - 1) / q)` is one
+    let factors = factorize(p - 1).expect("p - 1 is non-zero");
+    (1..p).find(|&g| {
+        factors
+            .iter()
+            .all(|&(q, _)| pow_mod(g as u64, ((p - 1) / q) as u64, p as u64) != 1)
+    })
+}
+
+/// Push the prime factors of `n` (which has no factors below 100) to `primes`
+fn split(n: usize, primes: &mut Vec<usize>) {
+    if n == 1 {
+        return;
+    }
+
+    if is_prime(n) {
+        primes.push(n);
+        return;
+    }
+
+    let divisor = pollard_rho(n);
+    split(divisor, primes);
+    split(n / divisor, primes);
+}
+
+/// A non-trivial divisor of the composite `n`
+fn pollard_rho(n: usize) -> usize {
+    let n64 = n as u64;
+    for c in 1_u128.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c) % n64 as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y) as usize, n);
+        }
+
+        if d != n {
+            return d;
+        }
+    }
+
+    unreachable!("a composite number has a divisor")
+}
+
+/// Euclid's algorithm on unsigned integers, which may be too large for [crate::euclid]
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exp: u64, n: u64) -> u64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, n);
+        }
+        base = mul_mod(base, base, n);
+        exp >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primes_and_factors() {
+        let small: Vec<usize> = (0..60).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            small,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59]
+        );
+
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime(usize::MAX - 58));
+        assert!(!is_prime(3215031751)); // strong pseudoprime to the bases 2, 3, 5 and 7
+        assert!(!is_prime(4294967297)); // 641 * 6700417
+
+        assert_eq!(next_prime(13), Some(17));
+        assert_eq!(next_prime((1 << 63) - 26), Some((1 << 63) - 25));
+        assert_eq!(next_prime(usize::MAX - 58), None);
+
+        assert_eq!(factorize(0), None);
+        assert_eq!(factorize(1), Some(vec![]));
+        assert_eq!(factorize(360), Some(vec![(2, 3), (3, 2), (5, 1)]));
+        assert_eq!(factorize(4294967297), Some(vec![(641, 1), (6700417, 1)]));
+        assert_eq!(
+            factorize(1000000007 * 998244353),
+            Some(vec![(998244353, 1), (1000000007, 1)])
+        );
+
+        assert_eq!(primitive_root(2), Some(1));
+        assert_eq!(primitive_root(7), Some(3));
+        assert_eq!(primitive_root(998244353), Some(3));
+        assert_eq!(primitive_root(9), None);
+    }
+}
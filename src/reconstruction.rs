@@ -0,0 +1,136 @@
+//! Rational reconstruction of numbers and functions from modular residues
+
+use crate::euclid::{extended_euclidean, partial_extended_euclidean};
+use crate::polynomial::Polynomial;
+use crate::ring::{Field, IntegersModuloAny, IntegersModuloP, PolynomialRing};
+
+/// Reconstruct a fraction `num / den` from its residue `elem` in `Z/pZ`
+///
+/// The numerator and denominator are bounded by `sqrt((p - 1) / 2)` in absolute value, which
+/// makes the result unique if it exists.
+///
+/// This will be `None` iff no such fraction exists.
+pub fn rational_reconstruction(field: IntegersModuloP, elem: isize) -> Option<(isize, usize)> {
+    let bound = ((field.n() - 1) / 2).isqrt();
+    rational_reconstruction_bounded(field, elem, bound, bound)
+}
+
+/// Reconstruct a fraction `num / den` from its residue `elem` in `Z/pZ`
+///
+/// The result satisfies `|num| <= num_bound` and `0 < den <= den_bound`. It is unique if
+/// `2 * num_bound * den_bound < p`.
+///
+/// This will be `None` iff no such fraction exists.
+pub fn rational_reconstruction_bounded(
+    field: IntegersModuloP,
+    elem: isize,
+    num_bound: usize,
+    den_bound: usize,
+) -> Option<(isize, usize)> {
+    // Every residue is at most `p - 1`, a larger bound would stop at `p` itself with `den = 0`.
+    // The numerator must also fit into an `isize`.
+    let num_bound = num_bound.min(field.n() - 1).min(isize::MAX as usize) as i128;
+
+    // The truncated extended euclidean algorithm on `p` and `elem` like in
+    // `partial_extended_euclidean`, but in `i128` since `p` may not fit into an `isize`. Every
+    // remainder `r` satisfies `r = t * elem mod p`.
+    let (mut r0, mut r1) = (field.n() as i128, field.residue(elem) as i128);
+    let (mut t0, mut t1) = (0_i128, 1_i128);
+    while r1 > num_bound {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    let (num, den) = if t1 < 0 { (-r1, -t1) } else { (r1, t1) };
+    let den = usize::try_from(den)
+        .ok()
+        .filter(|&den| den != 0 && den <= den_bound)?;
+    Some((num as isize, den))
+}
+
+/// Reconstruct a rational function `num / den` from its residue `f` modulo `m`
+///
+/// The result satisfies `deg num < k` and `deg den <= deg m - k`. The denominator is made monic.
+///
+/// This will be `None` iff no such rational function exists or `m` is zero.
+pub fn rational_function_reconstruction<F: Field>(
+    field: F,
+    f: Polynomial<F>,
+    m: Polynomial<F>,
+    k: usize,
+) -> Option<(Polynomial<F>, Polynomial<F>)> {
+    let n = m.deg()?;
+    if k > n {
+        return None;
+    }
+
+    let poly_ring = PolynomialRing::new(field);
+    let (_, f) = f.polynomial_division(m.clone())?;
+    let (num, _, den) = partial_extended_euclidean(poly_ring, m.clone(), f, k);
+
+    let (gcd, _, _) = extended_euclidean(poly_ring, den.clone(), m)?;
+    if den.deg()? > n - k || gcd.deg() != Some(0) {
+        return None;
+    }
+
+    let normalize = Polynomial::constant(field, field.inv(den.lc())?);
+    Some((num * normalize.clone(), den * normalize))
+}
+
+/// Compute the Padé approximant `[l/m]` of `f`
+///
+/// This is a rational function `num / den` with `deg num <= l`, `deg den <= m` and `den(0) = 1`
+/// that agrees with `f` modulo `x^(l + m + 1)`.
+///
+/// This will be `None` iff no such rational function exists.
+pub fn pade_approximant<F: Field>(
+    field: F,
+    f: Polynomial<F>,
+    l: usize,
+    m: usize,
+) -> Option<(Polynomial<F>, Polynomial<F>)> {
+    let modulus = Polynomial::single(field, field.one(), l + m + 1);
+    let (num, den) = rational_function_reconstruction(field, f, modulus, l + 1)?;
+
+    let normalize = Polynomial::constant(field, field.inv(den.coeff(0))?);
+    Some((num * normalize.clone(), den * normalize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::Ring;
+
+    #[test]
+    fn reconstruct_fractions() {
+        let field = IntegersModuloP::new(1_000_003).unwrap();
+
+        let elem = field.mul(3, field.inv(7).unwrap());
+        assert_eq!(rational_reconstruction(field, elem), Some((3, 7)));
+
+        let elem = field.mul(-5, field.inv(11).unwrap());
+        assert_eq!(rational_reconstruction(field, elem), Some((-5, 11)));
+
+        assert_eq!(rational_reconstruction(field, 42), Some((42, 1)));
+        assert_eq!(
+            rational_reconstruction_bounded(field, 5, 2_000_000, 1),
+            Some((5, 1))
+        );
+
+        // The largest prime below 2^64, whose residues don't all fit into an `isize`
+        let field = IntegersModuloP::new(18446744073709551557).unwrap();
+        let elem = field.mul(-5, field.inv(11).unwrap());
+        assert_eq!(rational_reconstruction(field, elem), Some((-5, 11)));
+    }
+
+    #[test]
+    fn pade_geometric_series() {
+        let field = IntegersModuloP::new(101).unwrap();
+        let f = Polynomial::new(field, vec![1, 1, 1, 1, 1, 1]);
+
+        let (num, den) = pade_approximant(field, f, 0, 1).unwrap();
+        assert_eq!(num, Polynomial::constant(field, 1));
+        assert_eq!(den, Polynomial::new(field, vec![1, -1]));
+    }
+}
@@ -0,0 +1,123 @@
+//! Linear recurrences over fields
+
+use crate::polynomial::Polynomial;
+use crate::ring::Field;
+
+/// Berlekamp–Massey algorithm
+///
+/// This will compute the shortest linear recurrence that generates `sequence` as
+/// `(connection, length)`. The connection polynomial `1 + c_1 x + ... + c_L x^L` describes the
+/// recurrence `s_n + c_1 s_(n-1) + ... + c_L s_(n-L) = 0` for all `n >= L` where `L = length`.
+///
+/// Note that the degree of `connection` may be smaller than `length`.
+pub fn berlekamp_massey<F: Field>(field: F, sequence: &[F::Element]) -> (Polynomial<F>, usize) {
+    let mut connection = Polynomial::constant(field, field.one());
+    let mut previous = connection.clone();
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = field.one();
+
+    for (n, elem) in sequence.iter().enumerate() {
+        let discrepancy = (1..=length).fold(elem.clone(), |acc, i| {
+            field.add(acc, field.mul(connection.coeff(i), sequence[n - i].clone()))
+        });
+
+        if discrepancy == field.zero() {
+            shift += 1;
+            continue;
+        }
+
+        let factor = field
+            .div(discrepancy.clone(), previous_discrepancy.clone())
+            .expect("previous discrepancy is non-zero");
+        let correction = Polynomial::single(field, factor, shift) * previous.clone();
+
+        if 2 * length <= n {
+            previous = connection.clone();
+            connection -= correction;
+            length = n + 1 - length;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            connection -= correction;
+            shift += 1;
+        }
+    }
+
+    (connection, length)
+}
+
+/// Compute the `n`-th term of a linear recurrence (Fiduccia's algorithm)
+///
+/// The recurrence is given by its `connection` polynomial (see [berlekamp_massey]) and its
+/// `initial` terms `s_0, ..., s_(L-1)`. This reduces `x^n` modulo the characteristic polynomial
+/// so it only needs `O(log n)` polynomial multiplications.
+///
+/// This will be `None` iff `connection` does not start with `1` or has a degree larger than the
+/// number of initial terms.
+pub fn linear_recurrence_nth<F: Field>(
+    field: F,
+    connection: &Polynomial<F>,
+    initial: &[F::Element],
+    n: usize,
+) -> Option<F::Element> {
+    let length = initial.len();
+    if connection.coeff(0) != field.one() || connection.deg().is_some_and(|deg| deg > length) {
+        return None;
+    }
+
+    let characteristic = Polynomial::new(
+        field,
+        (0..=length)
+            .map(|i| connection.coeff(length - i))
+            .collect::<Vec<_>>(),
+    );
+
+    let x = Polynomial::single(field, field.one(), 1);
+    let reduced = x.pow_mod(n, characteristic)?;
+
+    Some(
+        initial
+            .iter()
+            .enumerate()
+            .fold(field.zero(), |acc, (i, s)| {
+                field.add(acc, field.mul(reduced.coeff(i), s.clone()))
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ring::{IntegersModuloP, Ring};
+
+    #[test]
+    fn fibonacci() {
+        let field = IntegersModuloP::new(1_000_003).unwrap();
+        let sequence = [0, 1, 1, 2, 3, 5, 8, 13];
+
+        let (connection, length) = berlekamp_massey(field, &sequence);
+        assert_eq!(connection, Polynomial::new(field, vec![1, -1, -1]));
+        assert_eq!(length, 2);
+
+        let (mut a, mut b) = (0, 1);
+        for _ in 0..90 {
+            (a, b) = (b, field.add(a, b));
+        }
+
+        let nth = linear_recurrence_nth(field, &connection, &sequence[..length], 90);
+        assert_eq!(nth, Some(a));
+    }
+
+    #[test]
+    fn shifted_impulse() {
+        let field = IntegersModuloP::new(7).unwrap();
+
+        let (connection, length) = berlekamp_massey(field, &[0, 0, 1]);
+        assert_eq!(connection, Polynomial::new(field, vec![1, 0, 0, -1]));
+        assert_eq!(length, 3);
+
+        let nth = linear_recurrence_nth(field, &connection, &[0, 0, 1], 8);
+        assert_eq!(nth, Some(1));
+    }
+}
@@ -0,0 +1,260 @@
+use std::fmt;
+
+use super::{Field, FiniteRing, IntegersModuloAny, IntegersModuloP, PolynomialRing, Ring};
+use crate::euclid::extended_euclidean;
+use crate::polynomial::Polynomial;
+use crate::primes::{is_prime, primitive_root};
+use crate::{Error, Result};
+
+/// The finite field `GF(p^k)` with `p^k` elements
+///
+/// The elements are the polynomials of degree less than `k` over `Z/pZ` modulo an irreducible
+/// polynomial of degree `k` (see [Self::modulus]). The generator `α` is the residue of `x`.
+///
+/// An element is stored as the number whose base-`p` digits are its coefficients, e.g. `α^3 + 1`
+/// in `GF(2^8)` is `0b1001 = 9`. The order `p^k` must fit into a `usize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaloisField {
+    p: usize,
+    k: usize,
+    order: usize,
+
+    /// The coefficients of the modulus below `x^k`, encoded like an element
+    modulus: usize,
+}
+
+impl GaloisField {
+    /// Construct the `GF(p^k)`
+    ///
+    /// Return `None` if `p` is not prime or `p^k` doesn't fit into a `usize`. Panics if `k` is
+    /// zero.
+    pub fn new(p: usize, k: usize) -> Option<Self> {
+        Self::try_new(p, k).ok()
+    }
+
+    /// Construct the `GF(p^k)`
+    ///
+    /// Fail with [Error::NotPrime] if `p` is not prime and with [Error::Overflow] if `p^k`
+    /// doesn't fit into a `usize`. Panics if `k` is zero.
+    ///
+    /// The modulus is the irreducible polynomial whose coefficients below `x^k` are the
+    /// smallest number in the encoding of the elements, e.g. `x^8 + x^4 + x^3 + x + 1` for
+    /// `GF(2^8)`. For `k = 1` it is `x - g` with the smallest primitive root `g` modulo `p`, so
+    /// that the generator is not zero.
+    pub fn try_new(p: usize, k: usize) -> Result<Self> {
+        assert!(k > 0, "the degree must not be zero");
+
+        IntegersModuloP::try_new(p)?;
+        let order = u32::try_from(k)
+            .ok()
+            .and_then(|k| p.checked_pow(k))
+            .ok_or(Error::Overflow)?;
+
+        let mut field = Self {
+            p,
+            k,
+            order,
+            modulus: 0,
+        };
+        field.modulus = if k == 1 {
+            p - primitive_root(p).expect("p is prime")
+        } else {
+            (0..order)
+                .find(|&modulus| is_irreducible(&field.with_modulus(modulus), p))
+                .expect("there is an irreducible polynomial of every degree")
+        };
+
+        Ok(field)
+    }
+
+    /// The characteristic `p`
+    pub fn p(&self) -> usize {
+        self.p
+    }
+
+    /// The degree `k` over `Z/pZ`
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// The field `Z/pZ` of the coefficients
+    pub fn base_field(&self) -> IntegersModuloP {
+        IntegersModuloP::new_unchecked(self.p)
+    }
+
+    /// The monic irreducible polynomial of degree `k` that defines the field
+    pub fn modulus(&self) -> Polynomial<IntegersModuloP> {
+        self.with_modulus(self.modulus)
+    }
+
+    /// The generator `α`, i.e. the residue of `x`
+    pub fn generator(&self) -> usize {
+        self.from_poly(&Polynomial::single(self.base_field(), 1, 1))
+    }
+
+    /// The residue of the integer `n`
+    pub fn from_int(&self, n: isize) -> usize {
+        self.base_field().residue(n)
+    }
+
+    /// The element as a polynomial in `α` of degree less than `k`
+    pub fn to_poly(&self, mut elem: usize) -> Polynomial<IntegersModuloP> {
+        let mut coefficients = Vec::with_capacity(self.k);
+        for _ in 0..self.k {
+            coefficients.push(self.base_field().reduce((elem % self.p) as i128));
+            elem /= self.p;
+        }
+
+        Polynomial::new(self.base_field(), coefficients)
+    }
+
+    /// The residue of the polynomial `poly` in `α`
+    pub fn from_poly(&self, poly: &Polynomial<IntegersModuloP>) -> usize {
+        let field = self.base_field();
+        let (_, r) = poly
+            .clone()
+            .polynomial_division(self.modulus())
+            .expect("the modulus is non-zero");
+
+        (0..self.k)
+            .rev()
+            .fold(0, |elem, deg| elem * self.p + field.residue(r.coeff(deg)))
+    }
+
+    /// The monic polynomial `x^k + modulus` where `modulus` is encoded like an element
+    fn with_modulus(&self, modulus: usize) -> Polynomial<IntegersModuloP> {
+        self.to_poly(modulus) + Polynomial::single(self.base_field(), 1, self.k)
+    }
+}
+
+/// Rabin's test: a monic `f` of degree `k` over `Z/pZ` is irreducible iff `f` divides
+/// `x^(p^k) - x` and `gcd(x^(p^(k/q)) - x, f) = 1` for all primes `q` dividing `k`
+fn is_irreducible(f: &Polynomial<IntegersModuloP>, p: usize) -> bool {
+    let field = f.ring();
+    let k = f.deg().expect("f is monic");
+    let x = Polynomial::single(field, 1, 1)
+        .pow_mod(1, f.clone())
+        .expect("f is non-zero");
+
+    // `x^(p^i) mod f` for `i = 1, ..., k`
+    let mut power = x.clone();
+    for i in 1..=k {
+        power = power.pow_mod(p, f.clone()).expect("f is non-zero");
+
+        if i < k && k.is_multiple_of(i) && is_prime(k / i) {
+            let (gcd, _, _) = extended_euclidean(
+                PolynomialRing::new(field),
+                power.clone() - x.clone(),
+                f.clone(),
+            )
+            .expect("f is non-zero");
+            if gcd.deg() != Some(0) {
+                return false;
+            }
+        }
+    }
+
+    power == x
+}
+
+impl Ring for GaloisField {
+    type Element = usize;
+
+    fn zero(&self) -> Self::Element {
+        0
+    }
+
+    fn one(&self) -> Self::Element {
+        1
+    }
+
+    fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        self.from_poly(&(self.to_poly(lhs) + self.to_poly(rhs)))
+    }
+
+    fn neg(&self, elem: Self::Element) -> Self::Element {
+        self.from_poly(&-self.to_poly(elem))
+    }
+
+    fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        self.from_poly(&(self.to_poly(lhs) * self.to_poly(rhs)))
+    }
+
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        self.inv(elem).ok_or(Error::DivisionByZero)
+    }
+}
+
+impl FiniteRing for GaloisField {
+    fn characteristic(&self) -> usize {
+        self.p
+    }
+
+    fn order(&self) -> usize {
+        self.order
+    }
+
+    fn elements(&self) -> impl Iterator<Item = Self::Element> {
+        0..self.order
+    }
+}
+
+/// The inverse is the Bézout coefficient of the element and the (coprime) modulus
+impl Field for GaloisField {
+    fn inv(&self, elem: Self::Element) -> Option<Self::Element> {
+        if elem == 0 {
+            return None;
+        }
+
+        let ring = PolynomialRing::new(self.base_field());
+        let (_, s, _) = extended_euclidean(ring, self.to_poly(elem), self.modulus())?;
+        Some(self.from_poly(&s))
+    }
+}
+
+impl fmt::Display for GaloisField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GF({}^{})", self.p, self.k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::display::DisplayOptions;
+
+    #[test]
+    fn aes_field() {
+        let field = GaloisField::new(2, 8).unwrap();
+        assert_eq!(field.modulus().to_string(), "x^8 + x^4 + x^3 + x + 1");
+        assert_eq!(field.order(), 256);
+
+        // The example of FIPS 197, section 4.2
+        assert_eq!(field.mul(0x57, 0x83), 0xc1);
+        assert_eq!(field.add(0x57, 0x83), 0xd4);
+        assert!(field
+            .elements()
+            .skip(1)
+            .all(|elem| field.mul(elem, field.inv(elem).unwrap()) == 1));
+
+        let parse = |input| Polynomial::parse(field, input).unwrap();
+        let poly = parse("(α + 1)x^2 + α^8");
+        assert_eq!(poly.coeff(0), 0x1b);
+        assert_eq!(
+            poly.display(&DisplayOptions::default()).to_string(),
+            "(α + 1)x^2 + α^4 + α^3 + α + 1"
+        );
+
+        let (q, r) = parse("x^2 - α^2")
+            .polynomial_division(parse("x + α"))
+            .unwrap();
+        assert_eq!((q, r), (parse("x + α"), Polynomial::zero(field)));
+
+        assert_eq!(GaloisField::try_new(4, 2), Err(Error::NotPrime(4)));
+        assert_eq!(GaloisField::try_new(2, 64), Err(Error::Overflow));
+
+        let prime_field = GaloisField::new(7, 1).unwrap();
+        assert_eq!(prime_field.modulus().to_string(), "x + 4");
+        assert_eq!(prime_field.generator(), 3);
+    }
+}
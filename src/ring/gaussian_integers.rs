@@ -0,0 +1,171 @@
+use std::fmt;
+
+use super::Ring;
+use crate::{Error, Result};
+
+/// The ring `Z[i]` of Gaussian integers `a + bi` where `a` and `b` are integers
+#[derive(Debug, Clone, Copy)]
+pub struct GaussianIntegers;
+
+/// An element `re + im·i` of the [GaussianIntegers]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GaussianInteger {
+    pub re: isize,
+    pub im: isize,
+}
+
+impl GaussianInteger {
+    pub fn new(re: isize, im: isize) -> Self {
+        Self { re, im }
+    }
+
+    /// The imaginary unit `i`
+    pub fn i() -> Self {
+        Self::new(0, 1)
+    }
+
+    /// The complex conjugate `re - im·i`
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// The norm `re^2 + im^2`, i.e. the product with the conjugate
+    ///
+    /// This is a `u128` since the norm of large Gaussian integers doesn't fit into a `usize`.
+    pub fn norm(self) -> u128 {
+        (self.re.unsigned_abs() as u128).pow(2) + (self.im.unsigned_abs() as u128).pow(2)
+    }
+}
+
+impl From<isize> for GaussianInteger {
+    fn from(re: isize) -> Self {
+        Self::new(re, 0)
+    }
+}
+
+impl Ring for GaussianIntegers {
+    type Element = GaussianInteger;
+
+    fn zero(&self) -> Self::Element {
+        GaussianInteger::new(0, 0)
+    }
+
+    fn one(&self) -> Self::Element {
+        GaussianInteger::new(1, 0)
+    }
+
+    fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        GaussianInteger::new(lhs.re + rhs.re, lhs.im + rhs.im)
+    }
+
+    fn neg(&self, elem: Self::Element) -> Self::Element {
+        GaussianInteger::new(-elem.re, -elem.im)
+    }
+
+    fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        GaussianInteger::new(
+            lhs.re * rhs.re - lhs.im * rhs.im,
+            lhs.re * rhs.im + lhs.im * rhs.re,
+        )
+    }
+
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        Some(GaussianInteger::new(
+            lhs.re.checked_add(rhs.re)?,
+            lhs.im.checked_add(rhs.im)?,
+        ))
+    }
+
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        Some(GaussianInteger::new(
+            elem.re.checked_neg()?,
+            elem.im.checked_neg()?,
+        ))
+    }
+
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        let re = lhs
+            .re
+            .checked_mul(rhs.re)?
+            .checked_sub(lhs.im.checked_mul(rhs.im)?)?;
+        let im = lhs
+            .re
+            .checked_mul(rhs.im)?
+            .checked_add(lhs.im.checked_mul(rhs.re)?)?;
+        Some(GaussianInteger::new(re, im))
+    }
+
+    fn can_overflow(&self) -> bool {
+        true
+    }
+
+    /// The units are `1`, `-1`, `i` and `-i`, their inverse is the conjugate
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        match elem.norm() {
+            0 => Err(Error::DivisionByZero),
+            1 => Ok(elem.conj()),
+            _ => Err(Error::NotInvertible),
+        }
+    }
+}
+
+/// E.g. `3 + 4i`, `-2i` or `1 - i`
+impl fmt::Display for GaussianInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let imaginary = |im: usize| match im {
+            1 => String::from("i"),
+            _ => format!("{im}i"),
+        };
+
+        match (self.re, self.im) {
+            (re, 0) => write!(f, "{re}"),
+            (0, im) if im < 0 => write!(f, "-{}", imaginary(im.unsigned_abs())),
+            (0, im) => write!(f, "{}", imaginary(im.unsigned_abs())),
+            (re, im) if im < 0 => write!(f, "{re} - {}", imaginary(im.unsigned_abs())),
+            (re, im) => write!(f, "{re} + {}", imaginary(im.unsigned_abs())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::euclid::{extended_euclidean, EuclideanRing};
+    use crate::polynomial::Polynomial;
+
+    #[test]
+    fn gaussian_gcd() {
+        let z_i = GaussianIntegers;
+        let parse = |input| Polynomial::parse(z_i, input).unwrap().coeff(0);
+
+        let (a, b) = (parse("3 + 4i"), parse("1+3i"));
+        assert_eq!(
+            (a, b),
+            (GaussianInteger::new(3, 4), GaussianInteger::new(1, 3))
+        );
+        assert_eq!(parse("(1 + i)^2"), GaussianInteger::new(0, 2));
+
+        let (q, r) = GaussianIntegers::euclidean_division(a, b).unwrap();
+        assert_eq!(z_i.add(z_i.mul(q, b), r), a);
+        assert!(r.norm() < b.norm());
+
+        let (gcd, s, t) = extended_euclidean(z_i, a, b).unwrap();
+        assert_eq!(gcd, GaussianInteger::new(2, 1));
+        assert_eq!(z_i.add(z_i.mul(s, a), z_i.mul(t, b)), gcd);
+        assert_eq!(z_i.normal_form(parse("-1 + 2i")), gcd);
+
+        let big = GaussianInteger::new(4_000_000_000_000_000_000, -3_000_000_000_000_000_000);
+        let (q, r) = GaussianIntegers::euclidean_division(big, b).unwrap();
+        assert_eq!(z_i.add(z_i.mul(q, b), r), big);
+        assert!(r.norm() < b.norm());
+
+        let poly = Polynomial::parse(z_i, "(2 - i)x^2 - ix - 3").unwrap();
+        assert_eq!(poly.to_string(), "(2 - i)x^2 - ix - 3");
+        assert_eq!(z_i.try_inv(GaussianInteger::i()), Ok(parse("-i")));
+        assert_eq!(z_i.try_inv(parse("1 + i")), Err(Error::NotInvertible));
+
+        let large = GaussianInteger::new(isize::MIN, isize::MAX);
+        assert_eq!(large.norm(), (1 << 126) + (isize::MAX as u128).pow(2));
+        assert_eq!(z_i.try_inv(large), Err(Error::NotInvertible));
+    }
+}
@@ -26,4 +26,20 @@ impl Ring for Integers {
     fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
         lhs * rhs
     }
+
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_add(rhs)
+    }
+
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        elem.checked_neg()
+    }
+
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_mul(rhs)
+    }
+
+    fn can_overflow(&self) -> bool {
+        true
+    }
 }
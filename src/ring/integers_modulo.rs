@@ -1,20 +1,53 @@
 use std::fmt;
 
-use super::{Field, Ring};
+use super::{Field, FiniteRing, Ring};
+use crate::primes::is_prime;
+use crate::{Error, Result};
+
+/// The largest supported modulus `2^64 - 1`
+///
+/// Residues above `isize::MAX` are represented by the negative elements `r - n`, see
+/// [IntegersModuloN].
+pub const MAX_MODULUS: usize = usize::MAX;
 
 pub(crate) trait IntegersModuloAny: Copy + fmt::Debug {
     fn n(&self) -> usize;
+
+    /// The residue in `0..n` that `elem` represents
+    fn residue(&self, elem: isize) -> usize {
+        (elem as i128).rem_euclid(self.n() as i128) as usize
+    }
+
+    /// The element that represents the integer `elem`
+    fn reduce(&self, elem: i128) -> isize {
+        reduce(elem, self.n())
+    }
 }
 
 /// The ring `Z/nZ` of integers modulo `n`
+///
+/// The arithmetic is exact for all moduli up to [MAX_MODULUS]. Every residue `r` in `0..n` is
+/// represented by `r` itself if it fits into an `isize` and by the negative `r - n` otherwise.
+/// The operations accept any `isize` and treat it as the integer it is.
 #[derive(Clone, Copy)]
 pub struct IntegersModuloN {
     n: usize,
 }
 
 impl IntegersModuloN {
+    /// Construct the `Z/nZ`
+    ///
+    /// Panics if `n` is zero, see [Self::try_new].
     pub fn new(n: usize) -> Self {
-        Self { n }
+        Self::try_new(n).expect("the modulus must not be zero")
+    }
+
+    /// Construct the `Z/nZ`
+    ///
+    /// Fail with [Error::ZeroModulus] if `n` is zero
+    pub fn try_new(n: usize) -> Result<Self> {
+        check_modulus(n)?;
+        Ok(Self { n })
     }
 }
 
@@ -35,7 +68,10 @@ pub struct IntegersModuloP {
 
 impl IntegersModuloP {
     /// Construct the `Z/pZ` where `p` *must* be prime
+    ///
+    /// Panics if `p` is zero.
     pub fn new_unchecked(p: usize) -> Self {
+        assert!(check_modulus(p).is_ok(), "the modulus must not be zero");
         Self { p }
     }
 
@@ -43,7 +79,18 @@ impl IntegersModuloP {
     ///
     /// Return `None` if `p` is not prime
     pub fn new(p: usize) -> Option<Self> {
-        is_prime(p).then(|| IntegersModuloP::new_unchecked(p))
+        Self::try_new(p).ok()
+    }
+
+    /// Construct the `Z/pZ` and check if `p` is prime
+    ///
+    /// Fail with [Error::NotPrime] if `p` is not prime
+    pub fn try_new(p: usize) -> Result<Self> {
+        if is_prime(p) {
+            Ok(Self { p })
+        } else {
+            Err(Error::NotPrime(p))
+        }
     }
 }
 
@@ -64,28 +111,95 @@ impl<T: IntegersModuloAny> Ring for T {
         1
     }
 
+    // The results are computed in `isize` if possible and in `i128` otherwise, which is exact
+    // for all `isize` operands
     fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
-        self.id(lhs + rhs)
+        match lhs.checked_add(rhs) {
+            Some(sum) => self.id(sum),
+            None => self.reduce(lhs as i128 + rhs as i128),
+        }
     }
 
     fn neg(&self, elem: Self::Element) -> Self::Element {
-        self.id(-elem)
+        match elem.checked_neg() {
+            Some(neg) => self.id(neg),
+            None => self.reduce(-(elem as i128)),
+        }
     }
 
     fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
-        self.id(lhs * rhs)
+        match lhs.checked_mul(rhs) {
+            Some(product) => self.id(product),
+            None => self.reduce(lhs as i128 * rhs as i128),
+        }
     }
 
     fn id(&self, elem: Self::Element) -> Self::Element {
-        elem.rem_euclid(self.n() as isize)
+        match isize::try_from(self.n()) {
+            Ok(n) => elem.rem_euclid(n),
+            Err(_) => self.reduce(elem as i128),
+        }
+    }
+
+    /// `elem` is a unit iff it is coprime to `n`
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        inverse_modulo(elem, self.n()).ok_or(if self.id(elem) == 0 {
+            Error::DivisionByZero
+        } else {
+            Error::NotInvertible
+        })
+    }
+}
+
+impl<T: IntegersModuloAny> FiniteRing for T {
+    fn characteristic(&self) -> usize {
+        self.n()
+    }
+
+    fn order(&self) -> usize {
+        self.n()
+    }
+
+    fn elements(&self) -> impl Iterator<Item = Self::Element> {
+        (0..self.n()).map(|residue| self.reduce(residue as i128))
     }
 }
 
 impl Field for IntegersModuloP {
     fn inv(&self, elem: Self::Element) -> Option<Self::Element> {
-        let (_, s, _) = crate::euclid::extended_euclidean_int(elem, self.p as isize)?;
-        Some(s.rem_euclid(self.p as isize))
+        inverse_modulo(elem, self.p)
+    }
+}
+
+/// Fail if `n` is zero
+fn check_modulus(n: usize) -> Result<()> {
+    match n {
+        0 => Err(Error::ZeroModulus),
+        _ => Ok(()),
+    }
+}
+
+/// The element that represents the integer `elem` modulo `n`, see [IntegersModuloN]
+fn reduce(elem: i128, n: usize) -> isize {
+    let residue = elem.rem_euclid(n as i128);
+    match isize::try_from(residue) {
+        Ok(residue) => residue,
+        Err(_) => (residue - n as i128) as isize,
+    }
+}
+
+/// The inverse of `elem` modulo `n`, `None` if they are not coprime
+pub(crate) fn inverse_modulo(elem: isize, n: usize) -> Option<isize> {
+    let (mut r0, mut r1) = (n as i128, (elem as i128).rem_euclid(n as i128));
+    let (mut t0, mut t1) = (0_i128, 1_i128);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
     }
+
+    (r0 == 1).then(|| reduce(t0, n))
 }
 
 impl fmt::Debug for IntegersModuloN {
@@ -112,32 +226,10 @@ impl fmt::Display for IntegersModuloP {
     }
 }
 
-fn is_prime(p: usize) -> bool {
-    if p <= 1 {
-        return false;
-    }
-
-    if p == 2 || p == 3 {
-        return true;
-    }
-
-    if p % 2 == 0 || p % 3 == 0 {
-        return false;
-    }
-
-    let sqrt = (p as f32).sqrt().ceil() as usize;
-    for i in (5..=sqrt).step_by(6) {
-        if p % i == 0 || p % (i + 2) == 0 {
-            return false;
-        }
-    }
-
-    true
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::polynomial::Polynomial;
 
     #[test]
     fn prime_detection() {
@@ -161,4 +253,60 @@ mod tests {
         assert!(IntegersModuloP::new(127).is_some());
         assert!(IntegersModuloP::new(7793).is_some());
     }
+
+    #[test]
+    fn large_moduli() {
+        let mut seed: u64 = 7;
+        let mut random = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            seed
+        };
+
+        let mut moduli = vec![usize::MAX, 1 << 63, isize::MAX as usize, 3037000500, 12];
+        moduli.extend((0..20).map(|_| random() as usize | 1 << 40));
+
+        // The reference is exact since the products of two residues are below `2^128`
+        for n in moduli {
+            let ring = IntegersModuloN::new(n);
+            let n = n as u128;
+            let residue = |elem: isize| (elem as i128).rem_euclid(n as i128) as u128;
+
+            for _ in 0..200 {
+                let (x, y) = (random() as isize, random() as isize);
+                let (a, b) = (residue(x), residue(y));
+
+                assert_eq!(ring.residue(ring.add(x, y)) as u128, (a + b) % n);
+                assert_eq!(ring.residue(ring.sub(x, y)) as u128, (a + n - b) % n);
+                assert_eq!(ring.residue(ring.mul(x, y)) as u128, a * b % n);
+                assert_eq!(ring.id(ring.mul(x, y)), ring.mul(ring.id(x), ring.id(y)));
+            }
+
+            assert_eq!(
+                ring.residue(ring.id(isize::MIN)) as u128,
+                residue(isize::MIN)
+            );
+            assert_eq!(ring.mul(ring.neg(1), ring.neg(1)), 1);
+        }
+
+        // The largest prime below 2^64
+        let field = IntegersModuloP::new(18446744073709551557).unwrap();
+        for _ in 0..200 {
+            let elem = field.id(random() as isize);
+            if elem != 0 {
+                assert_eq!(field.mul(elem, field.inv(elem).unwrap()), 1);
+            }
+        }
+        assert_eq!(field.residue(field.neg(1)), 18446744073709551556);
+        assert_eq!(IntegersModuloN::try_new(0).err(), Some(Error::ZeroModulus));
+
+        let ring = IntegersModuloN::new(usize::MAX);
+        let parse = |input| Polynomial::parse(ring, input).unwrap();
+        let product = parse("18446744073709551614x + 3") * parse("x - 1");
+        assert_eq!(
+            product.to_string(),
+            "18446744073709551614x^2 + 4x + 18446744073709551612"
+        );
+    }
 }
@@ -1,16 +1,30 @@
 //! Algebraic (commutative) rings
 
+mod galois_field;
+mod gaussian_integers;
 mod integers;
 mod integers_modulo;
+mod montgomery;
 mod polynomial_ring;
+mod quadratic_field;
+mod rational;
 mod reals;
+mod spec;
 
+pub use galois_field::GaloisField;
+pub use gaussian_integers::{GaussianInteger, GaussianIntegers};
 pub use integers::Integers;
-pub use integers_modulo::{IntegersModuloN, IntegersModuloP};
+pub use integers_modulo::{IntegersModuloN, IntegersModuloP, MAX_MODULUS};
+pub use montgomery::MontgomeryModuloP;
 pub use polynomial_ring::PolynomialRing;
+pub use quadratic_field::{QuadraticField, QuadraticNumber};
+pub use rational::{Rational, Rationals};
 pub use reals::Reals;
+pub use spec::{Capabilities, RingSpec, RingSpecError};
 
-pub(crate) use integers_modulo::IntegersModuloAny;
+pub(crate) use integers_modulo::{inverse_modulo, IntegersModuloAny};
+
+use crate::{Error, Result};
 
 /// An algebraic commutative ring
 pub trait Ring: Copy + std::fmt::Debug {
@@ -27,9 +41,78 @@ pub trait Ring: Copy + std::fmt::Debug {
         self.add(lhs, self.neg(rhs))
     }
 
+    /// `lhs + rhs`, or `None` if the result doesn't fit into [Self::Element]
+    ///
+    /// Only rings whose elements are bounded machine integers like [Integers] can overflow.
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        Some(self.add(lhs, rhs))
+    }
+
+    /// `-elem`, or `None` if the result doesn't fit into [Self::Element]
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        Some(self.neg(elem))
+    }
+
+    /// `lhs * rhs`, or `None` if the result doesn't fit into [Self::Element]
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        Some(self.mul(lhs, rhs))
+    }
+
+    /// `lhs - rhs`, or `None` if the result doesn't fit into [Self::Element]
+    fn checked_sub(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        self.checked_add(lhs, self.checked_neg(rhs)?)
+    }
+
+    /// Whether the `checked_` operations can fail
+    ///
+    /// Fallible algorithms like [crate::euclid::try_extended_euclidean] only check every step
+    /// if this is `true`.
+    fn can_overflow(&self) -> bool {
+        false
+    }
+
     fn id(&self, elem: Self::Element) -> Self::Element {
         elem
     }
+
+    /// Whether the arithmetic is exact, i.e. free of rounding errors
+    ///
+    /// Algorithms that rely on exact cancellation, like the half-GCD, are only used if this is
+    /// `true`.
+    fn is_exact(&self) -> bool {
+        true
+    }
+
+    /// Whether `elem` has a multiplicative inverse, see [Self::try_inv]
+    fn is_unit(&self, elem: &Self::Element) -> bool {
+        self.try_inv(elem.clone()).is_ok()
+    }
+
+    /// The multiplicative inverse of `elem`
+    ///
+    /// Fail with [Error::DivisionByZero] for zero and [Error::NotInvertible] if `elem` is not a
+    /// unit. By default, only one and minus one are units.
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        if elem == self.one() || elem == self.neg(self.one()) {
+            Ok(elem)
+        } else if elem == self.zero() {
+            Err(Error::DivisionByZero)
+        } else {
+            Err(Error::NotInvertible)
+        }
+    }
+}
+
+/// A ring with finitely many elements
+pub trait FiniteRing: Ring {
+    /// The smallest `n > 0` so that the sum of `n` ones is zero
+    fn characteristic(&self) -> usize;
+
+    /// The number of elements
+    fn order(&self) -> usize;
+
+    /// All elements, starting with zero
+    fn elements(&self) -> impl Iterator<Item = Self::Element>;
 }
 
 /// An algebraic field
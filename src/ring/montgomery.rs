@@ -0,0 +1,232 @@
+use std::fmt;
+
+use super::{inverse_modulo, Field, FiniteRing, IntegersModuloP, Ring};
+use crate::{Error, Result};
+
+/// The field `Z/pZ` of integers modulo an odd prime `p` with elements in Montgomery form
+///
+/// An integer `a` is stored as `a * 2^64 mod p`, which makes multiplication free of divisions.
+/// This is faster than [IntegersModuloP] for long chains of multiplications like
+/// [crate::polynomial::Polynomial::pow_mod]. Elements are converted with [Self::from_int] and
+/// [Self::to_int], parsing and displaying polynomials does that automatically.
+#[derive(Clone, Copy)]
+pub struct MontgomeryModuloP {
+    p: u64,
+
+    /// `-p^(-1) mod 2^64`
+    p_neg_inv: u64,
+
+    /// `2^64 mod p`, i.e. the one in Montgomery form
+    r: u64,
+
+    /// `2^128 mod p`, used to convert into Montgomery form
+    r2: u64,
+}
+
+impl MontgomeryModuloP {
+    /// The bound `2^63 - 1` of the modulus, the reduction needs `p < 2^63`
+    pub const MAX_PRIME: usize = isize::MAX as usize;
+
+    /// Construct the `Z/pZ` where `p` *must* be an odd prime
+    ///
+    /// Panics if `p` is even or larger than [Self::MAX_PRIME].
+    pub fn new_unchecked(p: usize) -> Self {
+        assert!(
+            p % 2 == 1 && p <= Self::MAX_PRIME,
+            "the modulus is odd and in range"
+        );
+        let p = p as u64;
+
+        // Newton's iteration doubles the number of correct bits, `p * p = 1 mod 8` for odd `p`
+        let mut inv = p;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2_u64.wrapping_sub(p.wrapping_mul(inv)));
+        }
+
+        let r = ((1_u128 << 64) % p as u128) as u64;
+        let r2 = ((r as u128 * r as u128) % p as u128) as u64;
+
+        Self {
+            p,
+            p_neg_inv: inv.wrapping_neg(),
+            r,
+            r2,
+        }
+    }
+
+    /// Construct the `Z/pZ` and check if `p` is an odd prime
+    ///
+    /// Return `None` if `p` is not an odd prime
+    pub fn new(p: usize) -> Option<Self> {
+        Self::try_new(p).ok()
+    }
+
+    /// Construct the `Z/pZ` and check if `p` is an odd prime
+    ///
+    /// Fail with [Error::Overflow] if `p` is larger than [Self::MAX_PRIME] and with
+    /// [Error::NotPrime] if `p` is not an odd prime
+    pub fn try_new(p: usize) -> Result<Self> {
+        if p > Self::MAX_PRIME {
+            return Err(Error::Overflow);
+        }
+
+        IntegersModuloP::try_new(p)?;
+        if p == 2 {
+            Err(Error::NotPrime(p))
+        } else {
+            Ok(Self::new_unchecked(p))
+        }
+    }
+
+    /// The element that represents the integer `n`
+    pub fn from_int(&self, n: isize) -> u64 {
+        let n = (n as i128).rem_euclid(self.p as i128) as u64;
+        self.mul(n, self.r2)
+    }
+
+    /// The integer in `0..p` that `elem` represents
+    pub fn to_int(&self, elem: u64) -> isize {
+        self.reduce(elem as u128) as isize
+    }
+
+    pub(crate) fn p(&self) -> usize {
+        self.p as usize
+    }
+
+    /// Montgomery reduction, `t * 2^(-64) mod p` for `t < p * 2^64`
+    fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.p_neg_inv);
+
+        // This doesn't overflow because `p < 2^63`, the result is smaller than `2p`
+        let u = ((t + m as u128 * self.p as u128) >> 64) as u64;
+        if u >= self.p {
+            u - self.p
+        } else {
+            u
+        }
+    }
+}
+
+impl Ring for MontgomeryModuloP {
+    type Element = u64;
+
+    fn zero(&self) -> Self::Element {
+        0
+    }
+
+    fn one(&self) -> Self::Element {
+        self.r
+    }
+
+    fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        let sum = lhs + rhs;
+        if sum >= self.p {
+            sum - self.p
+        } else {
+            sum
+        }
+    }
+
+    fn neg(&self, elem: Self::Element) -> Self::Element {
+        if elem == 0 {
+            0
+        } else {
+            self.p - elem
+        }
+    }
+
+    fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        self.reduce(lhs as u128 * rhs as u128)
+    }
+
+    /// The other operations need the representatives in `0..p`
+    fn id(&self, elem: Self::Element) -> Self::Element {
+        elem % self.p
+    }
+
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        self.inv(elem).ok_or(Error::DivisionByZero)
+    }
+}
+
+impl FiniteRing for MontgomeryModuloP {
+    fn characteristic(&self) -> usize {
+        self.p()
+    }
+
+    fn order(&self) -> usize {
+        self.p()
+    }
+
+    fn elements(&self) -> impl Iterator<Item = Self::Element> {
+        (0..self.p()).map(|elem| self.from_int(elem as isize))
+    }
+}
+
+impl Field for MontgomeryModuloP {
+    fn inv(&self, elem: Self::Element) -> Option<Self::Element> {
+        inverse_modulo(self.to_int(elem), self.p()).map(|inverse| self.from_int(inverse))
+    }
+}
+
+impl fmt::Debug for MontgomeryModuloP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Z/{}Z", self.p)
+    }
+}
+
+impl fmt::Display for MontgomeryModuloP {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::Polynomial;
+
+    #[test]
+    fn agrees_with_integers_modulo_p() {
+        for p in [3, 1009, (1 << 61) - 1, (1 << 63) - 25] {
+            let plain = IntegersModuloP::new_unchecked(p);
+            let montgomery = MontgomeryModuloP::new_unchecked(p);
+            let mut seed: u64 = 3;
+
+            for _ in 0..500 {
+                let mut random = || {
+                    seed = seed
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (seed >> 1) as isize
+                };
+                let (a, b) = (random(), random());
+                let (x, y) = (montgomery.from_int(a), montgomery.from_int(b));
+
+                assert_eq!(montgomery.to_int(x), plain.id(a));
+                assert_eq!(montgomery.to_int(montgomery.add(x, y)), plain.add(a, b));
+                assert_eq!(montgomery.to_int(montgomery.sub(x, y)), plain.sub(a, b));
+                assert_eq!(montgomery.to_int(montgomery.mul(x, y)), plain.mul(a, b));
+                assert_eq!(
+                    montgomery.inv(x).map(|inv| montgomery.to_int(inv)),
+                    plain.inv(plain.id(a))
+                );
+            }
+        }
+
+        let field = MontgomeryModuloP::new(7).unwrap();
+        let poly = Polynomial::parse(field, "3x^2 + 1/2").unwrap();
+        assert_eq!(poly.to_string(), "3x^2 + 4");
+
+        let x = Polynomial::parse(field, "x + 1").unwrap();
+        assert_eq!(x.pow_mod(8, poly).unwrap().to_string(), "2x + 2");
+        assert!(MontgomeryModuloP::new(2).is_none());
+        assert_eq!(
+            MontgomeryModuloP::try_new(1 << 63).err(),
+            Some(Error::Overflow)
+        );
+
+        let poly = Polynomial::new(field, vec![100]);
+        assert_eq!(poly, Polynomial::new(field, vec![100 % 7]));
+    }
+}
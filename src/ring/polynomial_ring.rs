@@ -1,16 +1,34 @@
 use crate::polynomial::Polynomial;
 
 use super::Ring;
+use crate::{Error, Result};
 
 /// The ring `R[x]` of polynomials where `R` is another ring
 #[derive(Debug, Clone, Copy)]
 pub struct PolynomialRing<R: Ring> {
     ring: R,
+    variable: &'static str,
 }
 
 impl<R: Ring> PolynomialRing<R> {
     pub fn new(ring: R) -> Self {
-        Self { ring }
+        Self::with_variable(ring, "y")
+    }
+
+    /// Like [Self::new], but with a custom name for the variable, see [Self::variable]
+    pub fn with_variable(ring: R, variable: &'static str) -> Self {
+        Self { ring, variable }
+    }
+
+    /// The name of the variable when elements of this ring are coefficients of other
+    /// polynomials, e.g. `y` in `(y + 1)x^2` (`y` by default)
+    pub fn variable(&self) -> &'static str {
+        self.variable
+    }
+
+    /// The ring `R` of the coefficients
+    pub fn base_ring(&self) -> R {
+        self.ring
     }
 }
 
@@ -36,4 +54,39 @@ impl<R: Ring> Ring for PolynomialRing<R> {
     fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
         lhs * rhs
     }
+
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_add(rhs)
+    }
+
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        elem.checked_neg()
+    }
+
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_mul(rhs)
+    }
+
+    fn can_overflow(&self) -> bool {
+        self.ring.can_overflow()
+    }
+
+    fn is_exact(&self) -> bool {
+        self.ring.is_exact()
+    }
+
+    /// The constant polynomials whose coefficient is a unit
+    ///
+    /// Over an integral domain these are all the units. Otherwise, there are non-constant units
+    /// like `1 + 6x` over `Z/12Z` that are not detected.
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        match elem.deg() {
+            None => Err(Error::DivisionByZero),
+            Some(0) => Ok(Polynomial::constant(
+                self.ring,
+                self.ring.try_inv(elem.lc())?,
+            )),
+            Some(_) => Err(Error::NotInvertible),
+        }
+    }
 }
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::{Field, Rational, Ring};
+use crate::primes::factorize;
+use crate::{Error, Result};
+
+/// The quadratic number field `Q(√d)` for a squarefree integer `d ≠ 0, 1`
+///
+/// Its elements are `a + b√d` with rational `a` and `b`. Over this field, polynomials like
+/// `x^2 - d` split into linear factors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticField {
+    d: isize,
+}
+
+/// An element `a + b√d` of a [QuadraticField]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct QuadraticNumber {
+    pub a: Rational,
+    pub b: Rational,
+}
+
+impl QuadraticNumber {
+    pub fn new(a: Rational, b: Rational) -> Self {
+        Self { a, b }
+    }
+}
+
+impl From<Rational> for QuadraticNumber {
+    fn from(a: Rational) -> Self {
+        Self::new(a, Rational::zero())
+    }
+}
+
+impl QuadraticField {
+    /// Construct `Q(√d)` where `d` *must* be squarefree and neither 0 nor 1
+    pub fn new_unchecked(d: isize) -> Self {
+        Self { d }
+    }
+
+    /// Construct `Q(√d)` and check if `d` is squarefree and neither 0 nor 1
+    ///
+    /// Return `None` if `d` is invalid
+    pub fn new(d: isize) -> Option<Self> {
+        let squarefree =
+            factorize(d.unsigned_abs()).is_some_and(|factors| factors.iter().all(|&(_, e)| e == 1));
+
+        (squarefree && d != 1).then_some(Self { d })
+    }
+
+    /// Construct `Q(√d)` and check if `d` is squarefree and neither 0 nor 1
+    ///
+    /// Fail with [Error::NotSquarefree] if `d` is invalid
+    pub fn try_new(d: isize) -> Result<Self> {
+        Self::new(d).ok_or(Error::NotSquarefree(d))
+    }
+
+    /// The `d` of `Q(√d)`
+    pub fn d(&self) -> isize {
+        self.d
+    }
+
+    /// The element `√d`
+    pub fn sqrt_d(&self) -> QuadraticNumber {
+        QuadraticNumber::new(Rational::zero(), Rational::one())
+    }
+
+    /// The conjugate `a - b√d` of `a + b√d`
+    pub fn conj(&self, elem: QuadraticNumber) -> QuadraticNumber {
+        QuadraticNumber::new(elem.a, -elem.b)
+    }
+
+    /// The norm `a^2 - d·b^2`, i.e. the product with the conjugate
+    pub fn norm(&self, elem: QuadraticNumber) -> Rational {
+        elem.a * elem.a - Rational::from(self.d) * elem.b * elem.b
+    }
+
+    /// The trace `2a`, i.e. the sum with the conjugate
+    pub fn trace(&self, elem: QuadraticNumber) -> Rational {
+        elem.a + elem.a
+    }
+}
+
+impl Ring for QuadraticField {
+    type Element = QuadraticNumber;
+
+    fn zero(&self) -> Self::Element {
+        QuadraticNumber::default()
+    }
+
+    fn one(&self) -> Self::Element {
+        QuadraticNumber::from(Rational::one())
+    }
+
+    fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        QuadraticNumber::new(lhs.a + rhs.a, lhs.b + rhs.b)
+    }
+
+    fn neg(&self, elem: Self::Element) -> Self::Element {
+        QuadraticNumber::new(-elem.a, -elem.b)
+    }
+
+    fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        QuadraticNumber::new(
+            lhs.a * rhs.a + Rational::from(self.d) * lhs.b * rhs.b,
+            lhs.a * rhs.b + lhs.b * rhs.a,
+        )
+    }
+
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        Some(QuadraticNumber::new(
+            lhs.a.checked_add(rhs.a)?,
+            lhs.b.checked_add(rhs.b)?,
+        ))
+    }
+
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        Some(QuadraticNumber::new(
+            elem.a.checked_neg()?,
+            elem.b.checked_neg()?,
+        ))
+    }
+
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        let d = Rational::from(self.d);
+        let bb = lhs.b.checked_mul(rhs.b)?.checked_mul(d)?;
+        let ab = lhs.a.checked_mul(rhs.b)?;
+        let ba = lhs.b.checked_mul(rhs.a)?;
+
+        Some(QuadraticNumber::new(
+            lhs.a.checked_mul(rhs.a)?.checked_add(bb)?,
+            ab.checked_add(ba)?,
+        ))
+    }
+
+    fn can_overflow(&self) -> bool {
+        true
+    }
+
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        self.inv(elem).ok_or(Error::DivisionByZero)
+    }
+}
+
+/// The inverse is the conjugate divided by the norm, which is only zero for zero since `d` is
+/// not a square
+impl Field for QuadraticField {
+    fn inv(&self, elem: Self::Element) -> Option<Self::Element> {
+        let inverse = self.norm(elem).inv()?;
+        let conj = self.conj(elem);
+        Some(QuadraticNumber::new(conj.a * inverse, conj.b * inverse))
+    }
+}
+
+/// E.g. `1/2 - 3√d` or `(1/2)√d`, the element doesn't know its `d`
+impl fmt::Display for QuadraticNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = match (self.b.abs().numer(), self.b.denom()) {
+            (1, 1) => String::new(),
+            (_, 1) => self.b.abs().to_string(),
+            _ => format!("({})", self.b.abs()),
+        };
+
+        match (self.a.is_zero(), self.b.is_zero(), self.b.is_negative()) {
+            (_, true, _) => write!(f, "{}", self.a),
+            (true, false, false) => write!(f, "{b}√d"),
+            (true, false, true) => write!(f, "-{b}√d"),
+            (false, false, false) => write!(f, "{} + {b}√d", self.a),
+            (false, false, true) => write!(f, "{} - {b}√d", self.a),
+        }
+    }
+}
+
+impl fmt::Display for QuadraticField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Q(√{})", self.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::euclid::extended_euclidean;
+    use crate::polynomial::Polynomial;
+    use crate::ring::PolynomialRing;
+
+    #[test]
+    fn splitting_x2_minus_2() {
+        let field = QuadraticField::new(2).unwrap();
+        let parse = |input| Polynomial::parse(field, input).unwrap();
+
+        let sqrt_2 = field.sqrt_d();
+        assert_eq!(
+            field.mul(sqrt_2, sqrt_2),
+            field.add(field.one(), field.one())
+        );
+
+        let elem = parse("1/2 - 3√2").coeff(0);
+        assert_eq!(field.norm(elem), Rational::new(-71, 4));
+        assert_eq!(field.trace(elem), Rational::one());
+        assert_eq!(field.mul(elem, field.inv(elem).unwrap()), field.one());
+
+        let (q, r) = parse("x^2 - 2")
+            .polynomial_division(parse("x - √2"))
+            .unwrap();
+        assert_eq!((q, r), (parse("x + sqrt(2)"), parse("0")));
+
+        let poly = parse("x^2 - 2√2x + 2");
+        assert_eq!(poly.to_string(), "x^2 - (2√2)x + 2");
+
+        let ring = PolynomialRing::new(field);
+        let (gcd, _, _) = extended_euclidean(ring, parse("x^2 - 2"), poly).unwrap();
+        assert_eq!(gcd, parse("x - √2"));
+
+        assert_eq!(parse("0.25 + 1.5√2").to_string(), "1/4 + (3/2)√2");
+        assert_eq!(parse("0.25 + 1.5√2").coeff(0).to_string(), "1/4 + (3/2)√d");
+
+        // The coefficients of the power exceed an `isize`
+        let power = parse("(x + 1 + √2)^60");
+        let base = parse("1 + √2").coeff(0);
+        let constant = (0..60).fold(field.one(), |acc, _| field.mul(acc, base));
+        assert_eq!(power.coeff(0), constant);
+        assert!(constant.a.numer() > isize::MAX as i128);
+        assert!(Polynomial::parse(field, "(x + 1 + √2)^200").is_err());
+        assert!(QuadraticField::new(12).is_none());
+        assert_eq!(QuadraticField::try_new(1), Err(Error::NotSquarefree(1)));
+        assert!(QuadraticField::new(-1).is_some());
+    }
+}
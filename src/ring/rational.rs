@@ -0,0 +1,297 @@
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use super::{Field, Ring};
+use crate::{Error, Result};
+
+/// The field `Q` of rational numbers
+///
+/// The operations panic if a result doesn't fit into a [Rational], the parser of polynomials
+/// uses the `checked_` operations instead.
+#[derive(Debug, Clone, Copy)]
+pub struct Rationals;
+
+/// A fraction `num / den` of integers in lowest terms with a positive denominator
+///
+/// This is the element type of [Rationals] and the number type of the parts of
+/// [super::QuadraticField] elements. The arithmetic is exact with 128-bit numerators and
+/// denominators. The operators panic if a reduced result doesn't fit, the `checked_` methods
+/// return `None` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    /// The fraction `num / den` in lowest terms
+    ///
+    /// Panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "the denominator must not be zero");
+        Self::reduce(num, den).expect("the fraction fits into an i128")
+    }
+
+    pub fn zero() -> Self {
+        Self { num: 0, den: 1 }
+    }
+
+    pub fn one() -> Self {
+        Self { num: 1, den: 1 }
+    }
+
+    /// The numerator, which carries the sign
+    pub fn numer(&self) -> i128 {
+        self.num
+    }
+
+    /// The denominator, which is always positive
+    pub fn denom(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.num < 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    pub fn abs(self) -> Self {
+        Self {
+            num: self.num.abs(),
+            den: self.den,
+        }
+    }
+
+    /// The reciprocal `den / num`, `None` for zero
+    pub fn inv(self) -> Option<Self> {
+        (self.num != 0).then(|| Self::reduce(self.den, self.num).expect("the inverse fits"))
+    }
+
+    /// `self + rhs`, `None` on overflow
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        // Dividing by the GCD of the denominators keeps the intermediate results small
+        let gcd = gcd(self.den, rhs.den);
+        let (lhs_factor, rhs_factor) = (rhs.den / gcd, self.den / gcd);
+
+        let num = self
+            .num
+            .checked_mul(lhs_factor)?
+            .checked_add(rhs.num.checked_mul(rhs_factor)?)?;
+        Self::reduce(num, self.den.checked_mul(lhs_factor)?)
+    }
+
+    /// `-self`, `None` on overflow
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// `self * rhs`, `None` on overflow
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancel crosswise first, then the product is already in lowest terms
+        let (gcd_1, gcd_2) = (gcd(self.num, rhs.den), gcd(rhs.num, self.den));
+        Some(Self {
+            num: (self.num / gcd_1).checked_mul(rhs.num / gcd_2)?,
+            den: (self.den / gcd_2).checked_mul(rhs.den / gcd_1)?,
+        })
+    }
+
+    /// Parse an integer like `-12` or a decimal fraction like `2.25`
+    ///
+    /// The digits without the decimal point must fit into an `isize`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let (negative, rest) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input),
+        };
+        let (int, frac) = rest.split_once('.').unwrap_or((rest, ""));
+
+        let digits = format!("{int}{frac}");
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let num = digits.parse::<isize>().ok()? as i128;
+        let den = 10_i128.checked_pow(frac.len() as u32)?;
+        Some(Self::new(if negative { -num } else { num }, den))
+    }
+
+    /// Bring `num / den` (with `den != 0`) into lowest terms, `None` on overflow
+    fn reduce(num: i128, den: i128) -> Option<Self> {
+        let gcd = gcd(num, den);
+        let (num, den) = (num / gcd, den / gcd);
+
+        if den < 0 {
+            Some(Self {
+                num: num.checked_neg()?,
+                den: den.checked_neg()?,
+            })
+        } else {
+            Some(Self { num, den })
+        }
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(n: isize) -> Self {
+        Self {
+            num: n as i128,
+            den: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("the sum fits into an i128")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("the negation fits into an i128")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("the product fits into an i128")
+    }
+}
+
+impl Ring for Rationals {
+    type Element = Rational;
+
+    fn zero(&self) -> Self::Element {
+        Rational::zero()
+    }
+
+    fn one(&self) -> Self::Element {
+        Rational::one()
+    }
+
+    fn add(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        lhs + rhs
+    }
+
+    fn neg(&self, elem: Self::Element) -> Self::Element {
+        -elem
+    }
+
+    fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
+        lhs * rhs
+    }
+
+    fn checked_add(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_add(rhs)
+    }
+
+    fn checked_neg(&self, elem: Self::Element) -> Option<Self::Element> {
+        elem.checked_neg()
+    }
+
+    fn checked_mul(&self, lhs: Self::Element, rhs: Self::Element) -> Option<Self::Element> {
+        lhs.checked_mul(rhs)
+    }
+
+    fn can_overflow(&self) -> bool {
+        true
+    }
+
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        if elem.num == 0 {
+            return Err(Error::DivisionByZero);
+        }
+
+        // The sign moves to the numerator, which overflows for `i128::MIN`
+        Rational::reduce(elem.den, elem.num).ok_or(Error::Overflow)
+    }
+}
+
+impl Field for Rationals {
+    fn inv(&self, elem: Self::Element) -> Option<Self::Element> {
+        elem.inv()
+    }
+}
+
+/// E.g. `3`, `-1/2`
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// The non-negative GCD, which is only zero if both are
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a.abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::euclid::extended_euclidean;
+    use crate::polynomial::display::DisplayOptions;
+    use crate::polynomial::Polynomial;
+    use crate::ring::PolynomialRing;
+
+    #[test]
+    fn rational_polynomials() {
+        let parse = |input| Polynomial::parse(Rationals, input).unwrap();
+
+        let poly = parse("1/3x - 0.25");
+        assert_eq!(poly.coeff(1), Rational::new(1, 3));
+        assert_eq!(poly.coeff(0), Rational::new(-1, 4));
+        assert_eq!(poly.to_string(), "1/3x - 1/4");
+        assert_eq!(
+            poly.to_latex(&DisplayOptions::default()),
+            r"\frac{1}{3}x - \frac{1}{4}"
+        );
+
+        let (gcd, _, _) = extended_euclidean(
+            PolynomialRing::new(Rationals),
+            parse("x^2 - 1/4"),
+            parse("2x + 1"),
+        )
+        .unwrap();
+        assert_eq!(gcd.to_string(), "x + 1/2");
+        assert!(Polynomial::parse(Rationals, "170141183460469231731687303715884105727x").is_err());
+    }
+}
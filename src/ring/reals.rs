@@ -1,4 +1,5 @@
 use super::{Field, Ring};
+use crate::{Error, Result};
 
 /// The ring `R` of real numbers
 #[derive(Debug, Clone, Copy)]
@@ -26,6 +27,14 @@ impl Ring for Reals {
     fn mul(&self, lhs: Self::Element, rhs: Self::Element) -> Self::Element {
         lhs * rhs
     }
+
+    fn is_exact(&self) -> bool {
+        false
+    }
+
+    fn try_inv(&self, elem: Self::Element) -> Result<Self::Element> {
+        self.inv(elem).ok_or(Error::DivisionByZero)
+    }
 }
 
 impl Field for Reals {
@@ -0,0 +1,308 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{IntegersModuloP, MAX_MODULUS};
+
+/// A runtime description of a ring, parsed from strings like `Z/12Z[x]`
+///
+/// Supported notations (whitespace is ignored):
+/// - `R` or `ℝ`: the real numbers
+/// - `Z` or `ℤ`: the integers
+/// - `Z[i]` or `ℤ[i]`: the Gaussian integers
+/// - `Q` or `ℚ`: the rational numbers
+/// - `Z/nZ`, `ℤ/nℤ` or `Z_n`: the integers modulo `n`
+/// - `F_p`, `𝔽_p` or `GF(p)`: the finite field with `p` elements (`p` must be prime)
+/// - `GF(p^k)`: the finite field with `p^k` elements, see [super::GaloisField]
+/// - `R[x]`: polynomials over another ring `R` in the variable `x`, this can be repeated (but
+///   the variable `i` of `Z[i]` is the imaginary unit)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RingSpec {
+    Reals,
+    Integers,
+    GaussianIntegers,
+    Rationals,
+    IntegersModulo(usize),
+
+    /// The finite field `GF(p^k)` with `k > 1`
+    GaloisField {
+        p: usize,
+        k: usize,
+    },
+
+    /// The polynomial ring over `coefficients` in `variable`
+    Polynomials {
+        coefficients: Box<RingSpec>,
+        variable: String,
+    },
+}
+
+/// The capabilities of a ring described by a [RingSpec]
+///
+/// They correspond to the traits implemented by the ring in this library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// It is a [super::Field]
+    pub field: bool,
+
+    /// It is a [crate::euclid::EuclideanRing]
+    pub euclidean: bool,
+
+    /// It is a [super::FiniteRing]
+    pub finite: bool,
+
+    /// It is a [crate::polynomial::display::DisplayRing]
+    pub displayable: bool,
+
+    /// It is a [crate::polynomial::parse::ParsableRing]
+    pub parsable: bool,
+}
+
+/// The reason why a [RingSpec] could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RingSpecError {
+    /// The input is not a ring specification
+    Syntax(String),
+
+    /// The modulus of `Z/nZ` must be at least 2 and at most [super::MAX_MODULUS]
+    BadModulus(usize),
+
+    /// The number of elements of a finite field must be prime
+    NotPrime(usize),
+
+    /// The ring is known, but not implemented, e.g. a field `GF(p^k)` with more than
+    /// [super::MAX_MODULUS] elements
+    Unsupported(String),
+}
+
+impl fmt::Display for RingSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RingSpecError::Syntax(input) => write!(f, "`{input}` is not a ring specification"),
+            RingSpecError::BadModulus(n) => {
+                write!(f, "modulus {n} must be between 2 and {MAX_MODULUS}")
+            }
+            RingSpecError::NotPrime(p) => write!(f, "{p} is not prime"),
+            RingSpecError::Unsupported(ring) => write!(f, "the ring {ring} is not supported"),
+        }
+    }
+}
+
+impl std::error::Error for RingSpecError {}
+
+impl RingSpec {
+    /// Whether this is a finite field `F_p`
+    fn is_prime_field(&self) -> bool {
+        match self {
+            RingSpec::IntegersModulo(n) => IntegersModuloP::new(*n).is_some(),
+            _ => false,
+        }
+    }
+
+    /// What the described ring can do, see [Capabilities]
+    pub fn capabilities(&self) -> Capabilities {
+        let (field, euclidean) = match self {
+            RingSpec::Reals | RingSpec::Rationals => (true, false),
+            RingSpec::Integers | RingSpec::GaussianIntegers => (false, true),
+            RingSpec::IntegersModulo(_) => (self.is_prime_field(), false),
+            RingSpec::GaloisField { .. } => (true, false),
+            RingSpec::Polynomials { coefficients, .. } => {
+                (false, coefficients.capabilities().field)
+            }
+        };
+
+        Capabilities {
+            field,
+            euclidean,
+            finite: matches!(
+                self,
+                RingSpec::IntegersModulo(_) | RingSpec::GaloisField { .. }
+            ),
+            displayable: true,
+            parsable: true,
+        }
+    }
+}
+
+impl fmt::Display for RingSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RingSpec::Reals => write!(f, "R"),
+            RingSpec::Integers => write!(f, "Z"),
+            RingSpec::GaussianIntegers => write!(f, "Z[i]"),
+            RingSpec::Rationals => write!(f, "Q"),
+            RingSpec::IntegersModulo(n) => write!(f, "Z/{n}Z"),
+            RingSpec::GaloisField { p, k } => write!(f, "GF({p}^{k})"),
+            RingSpec::Polynomials {
+                coefficients,
+                variable,
+            } => write!(f, "{coefficients}[{variable}]"),
+        }
+    }
+}
+
+impl FromStr for RingSpec {
+    type Err = RingSpecError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let spec: String = input.split_whitespace().collect();
+        let syntax_error = || RingSpecError::Syntax(input.to_string());
+
+        // Strip the adjoined variables `[x]` from the end
+        let mut variables = Vec::new();
+        let mut base = spec.as_str();
+        while let Some(rest) = base.strip_suffix(']') {
+            let (rest, variable) = rest.rsplit_once('[').ok_or_else(syntax_error)?;
+            if variable.is_empty() || variable.contains(['[', ']']) {
+                return Err(syntax_error());
+            }
+
+            variables.push(variable.to_string());
+            base = rest;
+        }
+
+        let mut ring = parse_base(base).ok_or_else(syntax_error)??;
+        if ring == RingSpec::Integers && variables.last().is_some_and(|v| v == "i") {
+            variables.pop();
+            ring = RingSpec::GaussianIntegers;
+        }
+
+        for variable in variables.into_iter().rev() {
+            ring = RingSpec::Polynomials {
+                coefficients: Box::new(ring),
+                variable,
+            };
+        }
+
+        Ok(ring)
+    }
+}
+
+/// Parse a ring without adjoined variables, `None` if the syntax is unknown
+fn parse_base(base: &str) -> Option<Result<RingSpec, RingSpecError>> {
+    let number = |s: &str| s.parse::<usize>().ok();
+
+    let modulo = |n: usize| {
+        if !(2..=MAX_MODULUS).contains(&n) {
+            Err(RingSpecError::BadModulus(n))
+        } else {
+            Ok(RingSpec::IntegersModulo(n))
+        }
+    };
+    let prime_field = |p: usize| {
+        if IntegersModuloP::new(p).is_some() {
+            Ok(RingSpec::IntegersModulo(p))
+        } else {
+            Err(RingSpecError::NotPrime(p))
+        }
+    };
+
+    let ring = match base {
+        "R" | "ℝ" => Ok(RingSpec::Reals),
+        "Z" | "ℤ" => Ok(RingSpec::Integers),
+        "Q" | "ℚ" => Ok(RingSpec::Rationals),
+        _ => {
+            if let Some(n) = strip_affixes(base, &["Z/", "ℤ/"], &["Z", "ℤ"]) {
+                modulo(number(n)?)
+            } else if let Some(n) = strip_affixes(base, &["Z_"], &[""]) {
+                modulo(number(n)?)
+            } else if let Some(p) = strip_affixes(base, &["F_", "𝔽_"], &[""]) {
+                prime_field(number(p)?)
+            } else if let Some(order) = strip_affixes(base, &["GF("], &[")"]) {
+                let (p, k) = match order.split_once('^') {
+                    Some((p, k)) => (number(p)?, number(k)?),
+                    None => (number(order)?, 1),
+                };
+
+                match k {
+                    0 => return None,
+                    1 => prime_field(p),
+                    _ if IntegersModuloP::new(p).is_none() => Err(RingSpecError::NotPrime(p)),
+                    _ => {
+                        let order = u32::try_from(k).ok().and_then(|k| p.checked_pow(k));
+                        if order.is_some() {
+                            Ok(RingSpec::GaloisField { p, k })
+                        } else {
+                            Err(RingSpecError::Unsupported(format!("GF({p}^{k})")))
+                        }
+                    }
+                }
+            } else {
+                return None;
+            }
+        }
+    };
+
+    Some(ring)
+}
+
+/// The middle of `s` if it starts with one of the `prefixes` and ends with one of the `suffixes`
+fn strip_affixes<'a>(s: &'a str, prefixes: &[&str], suffixes: &[&str]) -> Option<&'a str> {
+    let rest = prefixes.iter().find_map(|prefix| s.strip_prefix(prefix))?;
+    suffixes.iter().find_map(|suffix| rest.strip_suffix(suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_specs() {
+        let parse = |s: &str| s.parse::<RingSpec>();
+        let poly = |coefficients, variable: &str| RingSpec::Polynomials {
+            coefficients: Box::new(coefficients),
+            variable: variable.to_string(),
+        };
+
+        assert_eq!(parse("R"), Ok(RingSpec::Reals));
+        assert_eq!(parse("ℤ"), Ok(RingSpec::Integers));
+        assert_eq!(parse("Z/12Z"), Ok(RingSpec::IntegersModulo(12)));
+        assert_eq!(parse("F_7"), Ok(RingSpec::IntegersModulo(7)));
+        assert_eq!(parse("GF(5)"), Ok(RingSpec::IntegersModulo(5)));
+        assert_eq!(
+            parse("Z/7Z [x][y]"),
+            Ok(poly(poly(RingSpec::IntegersModulo(7), "x"), "y"))
+        );
+        assert_eq!(parse("Z/7Z[x][y]").unwrap().to_string(), "Z/7Z[x][y]");
+        assert_eq!(parse("ℤ[i][x]"), Ok(poly(RingSpec::GaussianIntegers, "x")));
+        assert_eq!(parse("Q[x]"), Ok(poly(RingSpec::Rationals, "x")));
+        assert_eq!(
+            parse("Z[x][i]"),
+            Ok(poly(poly(RingSpec::Integers, "x"), "i"))
+        );
+
+        assert_eq!(parse("F_8"), Err(RingSpecError::NotPrime(8)));
+        assert_eq!(parse("Z/1Z"), Err(RingSpecError::BadModulus(1)));
+        assert_eq!(
+            parse("Z/18446744073709551615Z"),
+            Ok(RingSpec::IntegersModulo(usize::MAX))
+        );
+        assert_eq!(parse("GF(2^8)"), Ok(RingSpec::GaloisField { p: 2, k: 8 }));
+        assert_eq!(parse("GF(2^8)").unwrap().to_string(), "GF(2^8)");
+        assert_eq!(parse("GF(4^2)"), Err(RingSpecError::NotPrime(4)));
+        assert!(matches!(
+            parse("GF(2^64)"),
+            Err(RingSpecError::Unsupported(_))
+        ));
+        assert!(matches!(parse("Z[x"), Err(RingSpecError::Syntax(_))));
+        assert!(matches!(parse("Z[]"), Err(RingSpecError::Syntax(_))));
+    }
+
+    #[test]
+    fn capabilities() {
+        let capabilities = |s: &str| s.parse::<RingSpec>().unwrap().capabilities();
+
+        assert!(capabilities("R").field);
+        assert!(capabilities("Q[x]").euclidean);
+        assert!(capabilities("Z").euclidean);
+        assert!(capabilities("Z[i]").euclidean);
+        assert!(capabilities("F_7").field);
+        assert!(!capabilities("Z/12Z").field);
+        assert!(capabilities("F_7[x]").euclidean);
+        assert!(!capabilities("Z[x]").euclidean);
+        assert!(capabilities("Z[x][y]").parsable);
+        assert!(capabilities("Z/12Z").finite);
+        assert!(!capabilities("F_7[x]").finite);
+        assert!(capabilities("GF(3^4)").finite);
+        assert!(capabilities("GF(3^4)[x]").euclidean);
+    }
+}
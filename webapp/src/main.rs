@@ -3,10 +3,11 @@ mod operation;
 
 use leptos::prelude::*;
 
-use polymoly::polynomial::display::DisplayRing;
-use polymoly::polynomial::parse::ParsableRing;
-use polymoly::polynomial::Polynomial;
-use polymoly::ring::{Integers, IntegersModuloN, IntegersModuloP, PolynomialRing, Reals};
+use polymoly::dynamic::{DynPolynomial, DynRing};
+use polymoly::polynomial::display::{DisplayOptions, Format};
+use polymoly::polynomial::parse::ParseOptions;
+use polymoly::ring::RingSpec;
+use polymoly::Error;
 
 use operation::{OperandRingType, Operation};
 
@@ -29,11 +30,18 @@ fn App() -> impl IntoView {
     let (euclidean_ring, set_euclidean_ring) = signal(String::from("reals"));
 
     let (n, set_n) = signal(2_usize);
-    let do_select_n = move || match op.get().operand_ring_type() {
-        OperandRingType::Normal => normal_ring.get() == "modulo",
-        OperandRingType::Field => field_ring.get() == "modulo",
-        OperandRingType::Euclidean => euclidean_ring.get() == "modulo",
+
+    let (variable_input, set_variable_input) = signal(String::new());
+    let variable = move || match variable_input.get().trim() {
+        "" => String::from("x"),
+        v => v.to_string(),
+    };
+    let selected_ring = move || match op.get().operand_ring_type() {
+        OperandRingType::Normal => normal_ring.get(),
+        OperandRingType::Field => field_ring.get(),
+        OperandRingType::Euclidean => euclidean_ring.get(),
     };
+    let do_select_n = move || selected_ring() == "modulo";
 
     view! {
         <div class="radio-select">
@@ -93,9 +101,10 @@ fn App() -> impl IntoView {
                     on:change:target=move |ev| set_normal_ring.set(ev.target().value())
                     prop:value=move || normal_ring.get()
                 >
-                    <option value="reals">{ mathml::ring_string(mathml::LETTER_R, true) }</option>
-                    <option value="integers">{ mathml::ring_string(mathml::LETTER_Z, true) }</option>
-                    <option value="modulo">{ mathml::ring_string(mathml::integers_modulo_string("n"), true) }</option>
+                    <option value="reals">{ move || mathml::ring_string(mathml::reals(), true, variable()) }</option>
+                    <option value="integers">{ move || mathml::ring_string(mathml::integers(), true, variable()) }</option>
+                    <option value="gaussian">{ move || mathml::ring_string(mathml::gaussian_integers(), true, variable()) }</option>
+                    <option value="modulo">{ move || mathml::ring_string(mathml::integers_modulo_symbol("n"), true, variable()) }</option>
                 </select>
             </Show>
 
@@ -105,8 +114,8 @@ fn App() -> impl IntoView {
                     on:change:target=move |ev| set_field_ring.set(ev.target().value())
                     prop:value=move || field_ring.get()
                 >
-                    <option value="reals">{ mathml::ring_string(mathml::LETTER_R, true) }</option>
-                    <option value="modulo">{ mathml::ring_string(mathml::integers_modulo_string("p"), true) }</option>
+                    <option value="reals">{ move || mathml::ring_string(mathml::reals(), true, variable()) }</option>
+                    <option value="modulo">{ move || mathml::ring_string(mathml::integers_modulo_symbol("n"), true, variable()) }</option>
                 </select>
             </Show>
 
@@ -116,9 +125,10 @@ fn App() -> impl IntoView {
                     on:change:target=move |ev| set_euclidean_ring.set(ev.target().value())
                     prop:value=move || euclidean_ring.get()
                 >
-                    <option value="reals">{ mathml::ring_string(mathml::LETTER_R, true) }</option>
-                    <option value="modulo">{ mathml::ring_string(mathml::integers_modulo_string("p"), true) }</option>
-                    <option value="integers">{ mathml::ring_string(mathml::LETTER_Z, false) }</option>
+                    <option value="reals">{ move || mathml::ring_string(mathml::reals(), true, variable()) }</option>
+                    <option value="modulo">{ move || mathml::ring_string(mathml::integers_modulo_symbol("p"), true, variable()) }</option>
+                    <option value="integers">{ move || mathml::ring_string(mathml::integers(), false, variable()) }</option>
+                    <option value="gaussian">{ move || mathml::ring_string(mathml::gaussian_integers(), false, variable()) }</option>
                 </select>
             </Show>
 
@@ -126,8 +136,8 @@ fn App() -> impl IntoView {
                 <input type="number" min="2"
                     prop:placeholder=move || {
                         match op.get().operand_ring_type() {
-                            OperandRingType::Normal => "n",
-                            OperandRingType::Field | OperandRingType::Euclidean => "p",
+                            OperandRingType::Normal | OperandRingType::Field => "n",
+                            OperandRingType::Euclidean => "p",
                         }
                     }
                     on:input:target=move |ev| if let Ok(n_int) = ev.target().value().parse() {
@@ -136,6 +146,11 @@ fn App() -> impl IntoView {
                     prop:value=n.get().to_string()
                 />
             </Show>
+
+            <input type="text" class="variable"
+                prop:placeholder="x"
+                bind:value=(variable_input, set_variable_input)
+            />
         </div>
 
         // <button on:click=move |_| { }>
@@ -149,10 +164,9 @@ fn App() -> impl IntoView {
                         op.get(),
                         lhs.get(),
                         rhs.get(),
-                        normal_ring.get(),
-                        field_ring.get(),
-                        euclidean_ring.get(),
-                        n.get()
+                        selected_ring(),
+                        n.get(),
+                        variable(),
                     )
                 }}
             </p>
@@ -165,232 +179,135 @@ fn calculate(
     op: Operation,
     lhs: String,
     rhs: String,
-    normal_ring: String,
-    field_ring: String,
-    euclidean_ring: String,
+    ring: String,
     n: usize,
+    variable: String,
 ) -> impl IntoView {
-    match op {
-        Operation::Add => match normal_ring.as_str() {
-            "reals" => {
-                let (lhs, rhs) = match parse(Reals, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs + rhs).into_any()
-            }
-            "integers" => {
-                let (lhs, rhs) = match parse(Integers, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs + rhs).into_any()
-            }
-            "modulo" => {
-                let modulo = IntegersModuloN::new(n);
-                let (lhs, rhs) = match parse(modulo, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs + rhs).into_any()
-            }
-            _ => unreachable!(),
-        },
-        Operation::Sub => match normal_ring.as_str() {
-            "reals" => {
-                let (lhs, rhs) = match parse(Reals, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs - rhs).into_any()
-            }
-            "integers" => {
-                let (lhs, rhs) = match parse(Integers, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs - rhs).into_any()
-            }
-            "modulo" => {
-                let modulo = IntegersModuloN::new(n);
-                let (lhs, rhs) = match parse(modulo, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs - rhs).into_any()
-            }
-            _ => unreachable!(),
-        },
-        Operation::Mul => match normal_ring.as_str() {
-            "reals" => {
-                let (lhs, rhs) = match parse(Reals, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs * rhs).into_any()
-            }
-            "integers" => {
-                let (lhs, rhs) = match parse(Integers, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs * rhs).into_any()
-            }
-            "modulo" => {
-                let modulo = IntegersModuloN::new(n);
-                let (lhs, rhs) = match parse(modulo, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                mathml::render_polynomial(lhs * rhs).into_any()
-            }
-            _ => unreachable!(),
-        },
-        Operation::Div => match field_ring.as_str() {
-            "reals" => {
-                let (lhs, rhs) = match parse(Reals, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                let Some((q, r)) = lhs.polynomial_division(rhs) else {
-                    return view! { "Error: right-hand side must not be zero" }.into_any();
-                };
-
-                let has_rest = !r.is_zero();
-                view! {
-                    { mathml::render_polynomial(q) }
-                    <Show when=move || has_rest>
-                        <br />
-                        "REM "
-                        {
-                            let r = r.clone();
-                            mathml::render_polynomial(r)
-                        }
-                    </Show>
-                }
-                .into_any()
-            }
-            "modulo" => {
-                let Some(modulo) = IntegersModuloP::new(n) else {
-                    return view! { "Error: p must be prime" }.into_any();
-                };
-
-                let (lhs, rhs) = match parse(modulo, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                let Some((q, r)) = lhs.polynomial_division(rhs) else {
-                    return view! { "Error: right-hand side must not be zero" }.into_any();
-                };
-
-                let has_rest = !r.is_zero();
-                view! {
-                    { mathml::render_polynomial(q) }
-                    <Show when=move || has_rest>
-                        <br />
-                        "REM "
-                        {
-                            let r = r.clone();
-                            mathml::render_polynomial(r)
-                        }
-                    </Show>
-                }
-                .into_any()
-            }
-            _ => unreachable!(),
-        },
-        Operation::Gcd => match euclidean_ring.as_str() {
-            "integers" => {
-                let Ok(lhs) = lhs.parse::<isize>() else {
-                    return view! { {format!("Error: Couldn't parse integer {lhs}")} }.into_any();
-                };
-                let Ok(rhs) = rhs.parse::<isize>() else {
-                    return view! { {format!("Error: Couldn't parse integer {rhs}")} }.into_any();
-                };
-
-                let Some((gcd, s, t)) = polymoly::euclid::extended_euclidean_int(lhs, rhs) else {
-                    return view! { "Error: One side must be non-zero" }.into_any();
-                };
-
-                view! {
-                    { gcd }
-                    <br />
-                    "WITH s = " { s }
-                    " AND t = " { t }
-                }
-                .into_any()
-            }
-            "reals" => {
-                let (lhs, rhs) = match parse(Reals, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                let Some((gcd, s, t)) =
-                    polymoly::euclid::extended_euclidean(PolynomialRing::new(Reals), lhs, rhs)
-                else {
-                    return view! { "Error: One side must be non-zero" }.into_any();
-                };
-
-                view! {
-                    { mathml::render_polynomial(gcd) }
-                    <br />
-                    "WITH s = " { mathml::render_polynomial(s) }
-                    " AND t = " { mathml::render_polynomial(t) }
-                }
-                .into_any()
-            }
-            "modulo" => {
-                let Some(modulo) = IntegersModuloP::new(n) else {
-                    return view! { "Error: p must be prime" }.into_any();
-                };
-
-                let (lhs, rhs) = match parse(modulo, &lhs, &rhs) {
-                    Ok(x) => x,
-                    Err(err) => return view! { { err } }.into_any(),
-                };
-
-                let Some((gcd, s, t)) =
-                    polymoly::euclid::extended_euclidean(PolynomialRing::new(modulo), lhs, rhs)
-                else {
-                    return view! { "Error: One side must be non-zero" }.into_any();
-                };
-
-                view! {
-                    { mathml::render_polynomial(gcd) }
+    let display = DisplayOptions {
+        variable: variable.clone(),
+        ..Default::default()
+    };
+    let options = ParseOptions { variable };
+
+    // Composite moduli give `Z/nZ`, division works over it if the leading coefficient is a unit
+    let spec = match ring.as_str() {
+        "reals" => RingSpec::Reals,
+        "integers" => RingSpec::Integers,
+        "gaussian" => RingSpec::GaussianIntegers,
+        "modulo" => RingSpec::IntegersModulo(n),
+        _ => unreachable!(),
+    };
+    let ring = match DynRing::try_from(spec) {
+        Ok(ring) => ring,
+        Err(err) => return render_error("", err),
+    };
+
+    let parse = |input: &str| {
+        ring.parse(input, &options)
+            .map_err(|err| render_error(input, err))
+    };
+    let (lhs, rhs) = match parse(&lhs).and_then(|lhs| Ok((lhs, parse(&rhs)?))) {
+        Ok(x) => x,
+        Err(err) => return err,
+    };
+
+    let result = match op {
+        Operation::Add => lhs
+            .add(&rhs)
+            .map(|result| show_polynomial(result, &display)),
+        Operation::Sub => lhs
+            .sub(&rhs)
+            .map(|result| show_polynomial(result, &display)),
+        Operation::Mul => lhs
+            .mul(&rhs)
+            .map(|result| show_polynomial(result, &display)),
+        Operation::Div => lhs.div_rem(&rhs).and_then(|(q, r)| {
+            let latex = ring.render_division([&lhs, &rhs, &q, &r], Format::Latex, &display)?;
+            let has_rest = !r.is_zero();
+            let remainder_display = display.clone();
+            let result = view! {
+                { mathml::render_polynomial(&q, &display) }
+                <Show when=move || has_rest>
                     <br />
-                    "WITH s = " { mathml::render_polynomial(s) }
-                    " AND t = " { mathml::render_polynomial(t) }
-                }
-                .into_any()
-            }
-            _ => unreachable!(),
-        },
-    }
+                    "REM "
+                    { mathml::render_polynomial(&r, &remainder_display) }
+                </Show>
+            };
+
+            Ok(copyable(latex, result))
+        }),
+        Operation::Gcd => {
+            let operands = [lhs, rhs];
+            ring.extended_gcd(&operands)
+                .and_then(|(gcd, coefficients)| {
+                    let latex = ring.render_bezout(
+                        &gcd,
+                        &operands,
+                        &coefficients,
+                        Format::Latex,
+                        &display,
+                    )?;
+                    let [s, t] = &coefficients[..] else {
+                        unreachable!("two operands")
+                    };
+                    let result = view! {
+                        { mathml::render_polynomial(&gcd, &display) }
+                        <br />
+                        "WITH s = " { mathml::render_polynomial(s, &display) }
+                        " AND t = " { mathml::render_polynomial(t, &display) }
+                    };
+
+                    Ok(copyable(latex, result))
+                })
+        }
+    };
+
+    result.unwrap_or_else(|err| render_error("", err))
+}
+
+/// Show a polynomial that can be copied as LaTeX
+fn show_polynomial(poly: DynPolynomial, display: &DisplayOptions) -> AnyView {
+    copyable(
+        poly.render(Format::Latex, display),
+        mathml::render_polynomial(&poly, display),
+    )
 }
 
-fn parse<R>(ring: R, lhs: &str, rhs: &str) -> Result<(Polynomial<R>, Polynomial<R>), String>
-where
-    R: ParsableRing + DisplayRing,
-{
-    let Some(lhs) = Polynomial::parse(ring, lhs) else {
-        return Err(format!("Error: Couldn't parse polynomial {lhs}"));
+/// Show a result together with a button that copies its LaTeX source
+fn copyable(latex: String, result: impl IntoView + 'static) -> AnyView {
+    let copy = move |_| {
+        // The returned promise is not awaited, the copy happens in the background
+        let _ = window().navigator().clipboard().write_text(&latex);
     };
 
-    let Some(rhs) = Polynomial::parse(ring, rhs) else {
-        return Err(format!("Error: Couldn't parse polynomial {rhs}"));
+    view! {
+        { result }
+        <br />
+        <button class="copy-latex" on:click=copy>"Copy as LaTeX"</button>
+    }
+    .into_any()
+}
+
+/// Show an error, parse errors also mark the offending part of the `input`
+fn render_error(input: &str, err: Error) -> AnyView {
+    let Error::Parse(err) = err else {
+        return view! { { format!("Error: {err}") } }.into_any();
     };
 
-    Ok((lhs, rhs))
+    let (before, rest) = input.split_at(err.span.start);
+    let (token, after) = rest.split_at(err.span.len());
+
+    // Mark at least one character, even at the end of the input
+    let token = if token.is_empty() { " " } else { token };
+
+    view! {
+        { format!("Error: Couldn't parse polynomial: {err}") }
+        <br />
+        <code>
+            { before.to_string() }
+            <span class="parse-error">{ token.to_string() }</span>
+            { after.to_string() }
+        </code>
+    }
+    .into_any()
 }
@@ -1,54 +1,43 @@
-use leptos::either::Either;
-use leptos::math::{mi, mn, mo, msup};
 use leptos::prelude::*;
 
-use polymoly::polynomial::display::DisplayRing;
-use polymoly::polynomial::Polynomial;
+use polymoly::dynamic::DynPolynomial;
+use polymoly::polynomial::display::mathml::{adjoin, integers_modulo, math};
+use polymoly::polynomial::display::{DisplayOptions, DisplayRing, Format};
+use polymoly::ring::{GaussianIntegers, Integers, Reals};
 
-pub fn render_polynomial<R>(poly: Polynomial<R>) -> impl IntoView
-where
-    R: DisplayRing,
-    R::Element: std::fmt::Display,
-{
-    let x = poly
-        .map_display_parts(
-            |c| mn().child(c.to_string()),
-            |e| {
-                if let Some(e) = e {
-                    Either::Left(
-                        msup()
-                            .child(mi().child("x"))
-                            .child(mn().child(e.to_string())),
-                    )
-                } else {
-                    Either::Right(mi().child("x"))
-                }
-            },
-            || mo().child("+"),
-        )
-        .collect_view();
+pub fn render_polynomial(poly: &DynPolynomial, options: &DisplayOptions) -> impl IntoView {
+    render(poly.render(Format::MathMl, options))
+}
+
+/// Embed a rendered `<math>` element
+pub fn render(mathml: String) -> impl IntoView {
+    view! { <span inner_html=mathml></span> }
+}
 
-    view! { <math> { x } </math> }
+pub fn reals() -> String {
+    Reals.mathml_name()
 }
 
-pub const LETTER_R: &str = "ℝ";
-pub const LETTER_Z: &str = "ℤ";
+pub fn integers() -> String {
+    Integers.mathml_name()
+}
 
-pub fn integers_modulo_string(sub: &str) -> String {
-    format!("{LETTER_Z}/{sub}{LETTER_Z}")
+pub fn gaussian_integers() -> String {
+    GaussianIntegers.mathml_name()
 }
 
-pub fn ring_string(s: impl Into<String>, is_polynomial: bool) -> impl IntoView {
-    view! {
-        <math>
-            <mi>{s.into()}</mi>
-            <Show when=move || is_polynomial>
-                <mo>"["</mo>
-                <mi>"x"</mi>
-                <mo>"]"</mo>
-            </Show>
-        </math>
-    }
+pub fn integers_modulo_symbol(sub: &str) -> String {
+    integers_modulo(&format!("<mi>{sub}</mi>"))
+}
+
+pub fn ring_string(ring: String, is_polynomial: bool, variable: String) -> impl IntoView {
+    let fragment = if is_polynomial {
+        adjoin(&ring, &variable)
+    } else {
+        ring
+    };
+
+    render(math(&fragment))
 }
 
 pub fn operator_plus() -> impl IntoView {